@@ -1,13 +1,13 @@
-use std::fs::OpenOptions;
-use std::io::Read;
+use std::env;
+use std::fs::read_to_string;
+use std::process::Command;
 
 fn main() {
     pyo3_build_config::add_extension_module_link_args();
 
-    let mut f = OpenOptions::new().read(true).open("setup.cfg").unwrap();
-    let mut version = String::new();
-    f.read_to_string(&mut version).unwrap();
-    let version = version
+    let setup_cfg = read_to_string("setup.cfg")
+        .unwrap_or_else(|err| panic!("Could not read setup.cfg: {}", err));
+    let version = setup_cfg
         .lines()
         .find_map(|line| {
             const PREFIX: &str = "version = ";
@@ -16,7 +16,44 @@ fn main() {
             }
             Some(line[PREFIX.len()..].trim())
         })
-        .unwrap();
+        .unwrap_or_else(|| panic!("setup.cfg contains no line \"version = …\""));
     println!("cargo:rerun-if-changed=setup.cfg");
     println!("cargo:rustc-env=pyiced-version={}", version);
+
+    // plugins have to be compiled with the exact same compiler, ...
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let output = Command::new(&rustc)
+        .arg("--version")
+        .output()
+        .unwrap_or_else(|err| panic!("Could not run {:?} --version: {}", rustc, err));
+    let rustc_version = String::from_utf8(output.stdout)
+        .unwrap_or_else(|err| panic!("{:?} --version did not print UTF-8: {}", rustc, err));
+
+    // ... the exact same iced crates, ...
+    let manifest = read_to_string("Cargo.toml")
+        .unwrap_or_else(|err| panic!("Could not read Cargo.toml: {}", err));
+    let iced_versions: Vec<String> = manifest
+        .lines()
+        .filter(|line| line.starts_with("iced"))
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(" = ")?;
+            let version = rest.split("version = \"").nth(1)?.split('"').next()?;
+            Some(format!("{} {}", name, version))
+        })
+        .collect();
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    // ... and the same features. "default" is implied by the others, and "plugin" is the difference.
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(key, _)| Some(key.strip_prefix("CARGO_FEATURE_")?.to_lowercase()))
+        .filter(|feature| feature != "default" && feature != "plugin")
+        .collect();
+    features.sort();
+
+    println!(
+        "cargo:rustc-env=pyiced-abi={}; {}; features: {}",
+        rustc_version.trim(),
+        iced_versions.join(", "),
+        features.join(", "),
+    );
 }
@@ -11,7 +11,7 @@ use pyo3::wrap_pyfunction;
 use crate::async_tasks::vec_to_command;
 use crate::common::{debug_err, method_into_py, Message, ToNative};
 use crate::subscriptions::{ToSubscription, WrappedSubscription};
-use crate::widgets::WrappedWidgetBuilder;
+use crate::widgets::{animated_image_subscription, picture_subscription, WrappedWidgetBuilder};
 use crate::wrapped::{WrappedClipboard, WrappedColor, WrappedFont, WrappedIcon};
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -63,18 +63,8 @@ fn get_new_command(app: &PythonApp) -> Command<Message> {
     })
 }
 
-impl Application for PythonApp {
-    type Executor = executor::Default;
-    type Flags = Interop;
-    type Message = Message;
-
-    fn new(interop: Self::Flags) -> (PythonApp, Command<Message>) {
-        let app = PythonApp { interop };
-        let command = get_new_command(&app);
-        (app, command)
-    }
-
-    fn subscription(&self) -> Subscription<Message> {
+impl PythonApp {
+    fn python_subscription(&self) -> Subscription<Message> {
         let subscriptions = match &self.interop.subscriptions {
             Some(subscriptions) => subscriptions,
             None => return Subscription::none(),
@@ -116,6 +106,26 @@ impl Application for PythonApp {
             Subscription::batch(subscriptions)
         })
     }
+}
+
+impl Application for PythonApp {
+    type Executor = executor::Default;
+    type Flags = Interop;
+    type Message = Message;
+
+    fn new(interop: Self::Flags) -> (PythonApp, Command<Message>) {
+        let app = PythonApp { interop };
+        let command = get_new_command(&app);
+        (app, command)
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        Subscription::batch([
+            animated_image_subscription(),
+            picture_subscription(),
+            self.python_subscription(),
+        ])
+    }
 
     fn title(&self) -> String {
         match &self.interop.title {
@@ -15,7 +15,7 @@ pub(crate) fn init_mod(_py: Python, _m: &PyModule) -> PyResult<()> {
 }
 
 #[derive(Clone)]
-pub(crate) enum Message {
+pub enum Message {
     None,
     Native(Event),
     Python(Py<PyAny>),
@@ -41,7 +41,7 @@ impl Default for Message {
     }
 }
 
-pub(crate) trait ToNative {
+pub trait ToNative {
     fn to_native(&self, py: Python<'_>) -> Element<'static, Message>;
 }
 
@@ -74,7 +74,7 @@ pub(crate) fn method_into_py(py: Python, method: &PyAny) -> Option<Py<PyAny>> {
 }
 
 #[allow(unused_variables)]
-pub(crate) trait GCProtocol {
+pub trait GCProtocol {
     fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
         Ok(())
     }
@@ -1,7 +1,7 @@
 use iced::pane_grid::Line;
 use iced::rule::FillMode;
 use iced::scrollable::Scroller;
-use iced::{Background, Color, Vector};
+use iced::{Background, Color, Font, Vector};
 use pyo3::prelude::*;
 
 use crate::common::validate_f32;
@@ -12,7 +12,7 @@ use crate::styles::{
     WrappedTextInputStyle,
 };
 use crate::wrapped::{
-    SliderHandle, WrappedColor, WrappedFillMode, WrappedLine, WrappedSliderHandle,
+    SliderHandle, WrappedColor, WrappedFillMode, WrappedFont, WrappedLine, WrappedSliderHandle,
     WrappedSliderHandleShape,
 };
 
@@ -148,6 +148,25 @@ impl<'p> TryFrom<Extractor<'p>> for Scroller {
     }
 }
 
+impl<'p> TryFrom<Extractor<'p>> for Option<Font> {
+    type Error = PyErr;
+
+    fn try_from(value: Extractor<'p>) -> Result<Self, Self::Error> {
+        value
+            .0
+            .extract::<Option<_>>()
+            .map(|c| c.map(|WrappedFont(c)| c))
+    }
+}
+
+impl<'p> TryFrom<Extractor<'p>> for (u16, u16, u16, u16, u16, u16) {
+    type Error = PyErr;
+
+    fn try_from(value: Extractor<'p>) -> Result<Self, Self::Error> {
+        value.0.extract()
+    }
+}
+
 pub(crate) struct Unextractor<'a, Src>(pub &'a Src);
 
 pub(crate) trait Unextract<Dest> {
@@ -180,6 +199,12 @@ impl Unextract<Option<WrappedColor>> for Unextractor<'_, Option<Background>> {
     }
 }
 
+impl Unextract<Option<WrappedFont>> for Unextractor<'_, Option<Font>> {
+    fn unextract(self) -> Option<WrappedFont> {
+        self.0.map(WrappedFont)
+    }
+}
+
 impl<'a, T: Clone> Unextract<T> for Unextractor<'a, T> {
     fn unextract(self) -> T {
         self.0.clone()
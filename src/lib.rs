@@ -14,17 +14,21 @@
 
 #![allow(clippy::too_many_arguments)]
 
-use mimalloc::MiMalloc;
 use pyo3::prelude::*;
 
+#[cfg(not(feature = "plugin"))]
 #[global_allocator]
-static GLOBAL: MiMalloc = MiMalloc;
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+#[cfg(feature = "plugin")]
+#[global_allocator]
+static GLOBAL: plugin::PluginAllocator = plugin::PluginAllocator;
 
 macro_rules! init_mod {
-    ($(mod $name:ident $($feature:literal)?;)*) => {
+    ($($vis:vis mod $name:ident $($feature:literal)?;)*) => {
         $(
             $( #[cfg(feature = $feature)] )?
-            mod $name;
+            $vis mod $name;
         )*
 
         #[pymodule]
@@ -46,6 +50,7 @@ init_mod! {
     mod common;
     mod extractor;
     mod fontdb_integration "fontdb";
+    pub mod plugin;
     mod states;
     mod styles;
     mod subscriptions;
@@ -0,0 +1,271 @@
+//! Extension API for third-party PyO3 modules that want to provide their own widgets.
+//!
+//! A plugin crate depends on this crate with the feature "plugin" enabled, implements
+//! [`ToNative`] for its widget builder, and returns the result of [`into_capsule()`]
+//! to Python. The capsule can then be turned into a normal `Element` with `pyiced.external()`.
+//! The `#[pymodule]` function of the plugin has to call [`init()`] before anything else.
+//!
+//! Rust has no stable ABI, so the plugin must be compiled with the exact same compiler, the
+//! exact same version of pyiced and iced, and the same features. This is checked at runtime.
+//! With the feature "plugin" enabled, the plugin uses pyiced's global allocator, so that values
+//! can be freely moved across the module boundaries.
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::ffi::CStr;
+
+use mimalloc::MiMalloc;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+
+pub use crate::common::{GCProtocol, Message, ToNative};
+
+pub(crate) fn init_mod(py: Python, m: &PyModule) -> PyResult<()> {
+    let api = PluginApi {
+        abi_ptr: ABI.as_ptr(),
+        abi_len: ABI.len(),
+        alloc: api_alloc,
+        alloc_zeroed: api_alloc_zeroed,
+        dealloc: api_dealloc,
+        realloc: api_realloc,
+    };
+    m.add("_PLUGIN_API", PyCapsule::new(py, api, API_NAME)?)?;
+    Ok(())
+}
+
+/// The versions of pyiced, rustc and iced, and the enabled features, all of which have to match.
+pub const ABI: &str = concat!("pyiced ", env!("pyiced-version"), "; ", env!("pyiced-abi"));
+
+const API_NAME: &CStr = cstr(b"pyiced._pyiced._PLUGIN_API\0");
+
+const FACTORY_NAME: &CStr = cstr(b"pyiced.WidgetFactory\0");
+
+const fn cstr(bytes: &'static [u8]) -> &'static CStr {
+    // SAFETY: only used for literals defined in this module
+    unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+}
+
+/// A widget builder that can be turned into an `Element`.
+pub trait WidgetFactory: ToNative + Send + 'static {}
+
+impl<T: ToNative + Send + 'static> WidgetFactory for T {}
+
+#[repr(C)]
+struct PluginApi {
+    abi_ptr: *const u8,
+    abi_len: usize,
+    alloc: unsafe extern "C" fn(usize, usize) -> *mut u8,
+    alloc_zeroed: unsafe extern "C" fn(usize, usize) -> *mut u8,
+    dealloc: unsafe extern "C" fn(*mut u8, usize, usize),
+    realloc: unsafe extern "C" fn(*mut u8, usize, usize, usize) -> *mut u8,
+}
+
+// SAFETY: the pointers point to static data
+unsafe impl Send for PluginApi {}
+unsafe impl Sync for PluginApi {}
+
+unsafe extern "C" fn api_alloc(size: usize, align: usize) -> *mut u8 {
+    MiMalloc.alloc(Layout::from_size_align_unchecked(size, align))
+}
+
+unsafe extern "C" fn api_alloc_zeroed(size: usize, align: usize) -> *mut u8 {
+    MiMalloc.alloc_zeroed(Layout::from_size_align_unchecked(size, align))
+}
+
+unsafe extern "C" fn api_dealloc(ptr: *mut u8, size: usize, align: usize) {
+    MiMalloc.dealloc(ptr, Layout::from_size_align_unchecked(size, align))
+}
+
+unsafe extern "C" fn api_realloc(ptr: *mut u8, size: usize, align: usize, new: usize) -> *mut u8 {
+    MiMalloc.realloc(ptr, Layout::from_size_align_unchecked(size, align), new)
+}
+
+/// The global allocator of plugins. It forwards all calls to pyiced's allocator.
+///
+/// pyiced's allocator is only known after [`init()`] was called. The few allocations that
+/// happen before, e.g. while PyO3 prepares the module, are served from a static arena,
+/// which is never freed. The allocator itself never calls into Python.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PluginAllocator;
+
+/// Looks up pyiced's allocator. To be called in the `#[pymodule]` function of the plugin.
+#[cfg(feature = "plugin")]
+pub fn init(py: Python<'_>) -> PyResult<()> {
+    allocator::init(py)
+}
+
+#[cfg(feature = "plugin")]
+mod allocator {
+    use std::alloc::{GlobalAlloc, Layout};
+    use std::cell::UnsafeCell;
+    use std::ptr::{copy_nonoverlapping, null_mut};
+    use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+    use pyo3::exceptions::PyValueError;
+    use pyo3::ffi;
+    use pyo3::prelude::*;
+
+    use super::{ABI, API_NAME, PluginAllocator, PluginApi};
+
+    static API: AtomicPtr<PluginApi> = AtomicPtr::new(null_mut());
+
+    const ARENA_SIZE: usize = 1 << 20;
+
+    #[repr(C, align(4096))]
+    struct Arena(UnsafeCell<[u8; ARENA_SIZE]>);
+
+    // SAFETY: every allocation gets a distinct part of the arena
+    unsafe impl Sync for Arena {}
+
+    static ARENA: Arena = Arena(UnsafeCell::new([0; ARENA_SIZE]));
+    static ARENA_USED: AtomicUsize = AtomicUsize::new(0);
+
+    pub(super) fn init(py: Python<'_>) -> PyResult<()> {
+        if !API.load(Ordering::Acquire).is_null() {
+            return Ok(());
+        }
+
+        // SAFETY: the GIL is held
+        let api = unsafe { ffi::PyCapsule_Import(API_NAME.as_ptr(), 0) as *mut PluginApi };
+        if api.is_null() {
+            return Err(PyErr::fetch(py));
+        }
+
+        // SAFETY: the capsule contains a PluginApi, and its ABI string is static
+        let abi = unsafe { std::slice::from_raw_parts((*api).abi_ptr, (*api).abi_len) };
+        if abi != ABI.as_bytes() {
+            return Err(PyErr::new::<PyValueError, _>(
+                crate::format_to_string_ignore!(
+                    "The plugin was compiled for {:?}, but pyiced is {:?}",
+                    ABI,
+                    String::from_utf8_lossy(abi),
+                ),
+            ));
+        }
+
+        API.store(api, Ordering::Release);
+        Ok(())
+    }
+
+    fn api() -> Option<&'static PluginApi> {
+        let api = API.load(Ordering::Acquire);
+        // SAFETY: the pointer was checked in init(), and the capsule lives as long as pyiced
+        unsafe { api.as_ref() }
+    }
+
+    fn arena_alloc(layout: Layout) -> *mut u8 {
+        let base = ARENA.0.get() as usize;
+        let mut used = ARENA_USED.load(Ordering::Relaxed);
+        loop {
+            let start = (base + used + layout.align() - 1) & !(layout.align() - 1);
+            let end = start + layout.size();
+            if end > base + ARENA_SIZE {
+                // lets Rust abort with an "out of memory" message
+                return null_mut();
+            }
+            match ARENA_USED.compare_exchange_weak(
+                used,
+                end - base,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return start as *mut u8,
+                Err(current) => used = current,
+            }
+        }
+    }
+
+    fn is_in_arena(ptr: *mut u8) -> bool {
+        let base = ARENA.0.get() as usize;
+        (base..base + ARENA_SIZE).contains(&(ptr as usize))
+    }
+
+    unsafe impl GlobalAlloc for PluginAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            match api() {
+                Some(api) => (api.alloc)(layout.size(), layout.align()),
+                None => arena_alloc(layout),
+            }
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            match api() {
+                Some(api) => (api.alloc_zeroed)(layout.size(), layout.align()),
+                // the arena is never reused, so it is still zeroed
+                None => arena_alloc(layout),
+            }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            if is_in_arena(ptr) {
+                return;
+            }
+            if let Some(api) = api() {
+                (api.dealloc)(ptr, layout.size(), layout.align())
+            }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            match api() {
+                Some(api) if !is_in_arena(ptr) => {
+                    (api.realloc)(ptr, layout.size(), layout.align(), new_size)
+                },
+                _ => {
+                    let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+                    let new_ptr = self.alloc(new_layout);
+                    if !new_ptr.is_null() {
+                        copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+                    }
+                    new_ptr
+                },
+            }
+        }
+    }
+}
+
+#[repr(C)]
+struct FactoryCapsule {
+    abi_ptr: *const u8,
+    abi_len: usize,
+    factory: Box<dyn WidgetFactory>,
+}
+
+// SAFETY: the pointer points to static data, and the factory is Send
+unsafe impl Send for FactoryCapsule {}
+
+/// Wraps a widget factory into a capsule, that can be used in `pyiced.external()`.
+pub fn into_capsule<F: WidgetFactory>(py: Python<'_>, factory: F) -> PyResult<&PyCapsule> {
+    let content = FactoryCapsule {
+        abi_ptr: ABI.as_ptr(),
+        abi_len: ABI.len(),
+        factory: Box::new(factory),
+    };
+    PyCapsule::new(py, content, FACTORY_NAME)
+}
+
+/// Retrieves the widget factory that was wrapped by [`into_capsule()`].
+pub(crate) fn factory_from_capsule(capsule: &PyAny) -> PyResult<&dyn WidgetFactory> {
+    let capsule: &PyCapsule = match capsule.downcast() {
+        Ok(capsule) => capsule,
+        Err(_) => return Err(PyErr::new::<PyTypeError, _>("Expected a PyCapsule")),
+    };
+    if capsule.name() != FACTORY_NAME {
+        return Err(PyErr::new::<PyTypeError, _>(
+            "Expected a PyCapsule named \"pyiced.WidgetFactory\"",
+        ));
+    }
+
+    // SAFETY: the header is the same for all versions
+    let content = unsafe { capsule.reference::<FactoryCapsule>() };
+    let abi = unsafe { std::slice::from_raw_parts(content.abi_ptr, content.abi_len) };
+    if abi != ABI.as_bytes() {
+        return Err(PyErr::new::<PyValueError, _>(
+            crate::format_to_string_ignore!(
+                "The plugin was compiled for {:?}, expected {:?}",
+                String::from_utf8_lossy(abi),
+                ABI,
+            ),
+        ));
+    }
+    Ok(&*content.factory)
+}
@@ -6,24 +6,30 @@ from pyiced._pyiced import Commands, FloatPositive
 
 __all__ = [
     # states
-    'ButtonState', 'PickListState', 'ScrollableState', 'SliderState', 'TextInputState',
+    'ButtonState', 'ColorPickerState', 'ComboBoxState', 'DatePickerState', 'ImageViewerState',
+    'ListBoxState', 'NumberInputState', 'PickListState', 'ScrollableState', 'SliderState',
+    'TextInputState', 'TimePickerState',
 
     # widgets
-    'Element', 'no_element', 'button', 'checkbox', 'column', 'container', 'image', 'pick_list',
-    'progress_bar', 'radio', 'row', 'rule', 'scrollable', 'slider', 'space', 'svg', 'text',
-    'text_input', 'tooltip',
+    'Element', 'no_element', 'animated_image', 'button', 'checkbox', 'color_picker', 'column',
+    'combo_box', 'container', 'date_picker', 'external', 'grid', 'image', 'image_viewer',
+    'list_box', 'markdown', 'number_input', 'pick_list', 'progress_bar', 'radio', 'range_slider',
+    'rich_text', 'row', 'rule', 'scrollable', 'selectable_text', 'slider', 'space', 'span', 'stack',
+    'svg', 'text', 'text_input', 'time_picker', 'toggler', 'tooltip', 'wrap',
 
     # wrapped
-    'Align', 'Clipboard', 'Color', 'FillMode', 'Font', 'HorizontalAlignment', 'Icon', 'ImageHandle',
-    'Instant', 'Length', 'Line', 'Message', 'Point', 'Rectangle', 'SliderHandle',
-    'SliderHandleShape', 'SvgHandle', 'TextInputCursor', 'TooltipPosition', 'VerticalAlignment',
+    'Align', 'AnimatedImageHandle', 'Clipboard', 'Color', 'FillMode', 'Font', 'GridCell',
+    'HorizontalAlignment', 'Icon', 'Image', 'ImageHandle', 'Instant', 'Length', 'Line', 'Message',
+    'Point', 'Rectangle', 'SliderHandle', 'SliderHandleShape', 'SvgHandle', 'TextInputCursor',
+    'TextSpan', 'TooltipPosition', 'VerticalAlignment',
 
     # styles
     'ButtonStyle', 'ButtonStyleSheet', 'CheckboxStyle', 'CheckboxStyleSheet', 'ContainerStyleSheet',
-    'PaneGridStyleSheet', 'PickListMenu', 'PickListStyle', 'PickListStyleSheet',
-    'ProgressBarStyleSheet', 'RadioStyle', 'RadioStyleSheet', 'RuleStyleSheet',
-    'ScrollableStyleSheet', 'ScrollbarStyle', 'ScrollerStyle', 'Size', 'SliderStyle',
-    'SliderStyleSheet', 'TextInputStyle', 'TextInputStyleSheet',
+    'ListBoxStyle', 'MarkdownTheme', 'PaneGridStyleSheet', 'PickListMenu', 'PickListStyle',
+    'PickListStyleSheet', 'ProgressBarStyleSheet', 'RadioStyle', 'RadioStyleSheet',
+    'RuleStyleSheet', 'ScrollableStyleSheet', 'ScrollbarStyle', 'ScrollerStyle', 'Size',
+    'SliderStyle', 'SliderStyleSheet', 'TextInputStyle', 'TextInputStyleSheet', 'TogglerStyle',
+    'TogglerStyleSheet',
 
     # subscription
     'every', 'stream', 'Subscription',
@@ -1,33 +1,39 @@
-from datetime import timedelta
+from datetime import date, time, timedelta
 from functools import wraps
 from math import isnan, isinf, isfinite
 from pathlib import Path
 from typing import (
-    Annotated, Any, Awaitable, Callable, Iterable, Literal, Optional, Tuple, Union, final, get_args,
-    get_origin, get_type_hints, overload,
+    Annotated, Any, Awaitable, Callable, Container, Iterable, Literal, Optional, Sequence, Tuple,
+    Union, final, get_args, get_origin, get_type_hints, overload,
 )
 
 
 __all__ = [
     # states
-    'ButtonState', 'PickListState', 'ScrollableState', 'SliderState', 'TextInputState',
+    'ButtonState', 'ColorPickerState', 'ComboBoxState', 'DatePickerState', 'ImageViewerState',
+    'ListBoxState', 'NumberInputState', 'PickListState', 'ScrollableState', 'SliderState',
+    'TextInputState', 'TimePickerState',
 
     # widgets
-    'Element', 'no_element', 'button', 'checkbox', 'column', 'container', 'image', 'pick_list',
-    'progress_bar', 'radio', 'row', 'rule', 'scrollable', 'slider', 'space', 'svg', 'text',
-    'text_input', 'tooltip',
+    'Element', 'no_element', 'animated_image', 'button', 'checkbox', 'color_picker', 'column',
+    'combo_box', 'container', 'date_picker', 'external', 'grid', 'image', 'image_viewer',
+    'list_box', 'markdown', 'number_input', 'pick_list', 'progress_bar', 'radio', 'range_slider',
+    'rich_text', 'row', 'rule', 'scrollable', 'selectable_text', 'slider', 'space', 'span', 'stack',
+    'svg', 'text', 'text_input', 'time_picker', 'toggler', 'tooltip', 'wrap',
 
     # wrapped
-    'Align', 'Clipboard', 'Color', 'FillMode', 'Font', 'HorizontalAlignment', 'Icon', 'ImageHandle',
-    'Instant', 'Length', 'Line', 'Message', 'Point', 'Rectangle', 'SliderHandle',
-    'SliderHandleShape', 'SvgHandle', 'TextInputCursor', 'TooltipPosition', 'VerticalAlignment',
+    'Align', 'AnimatedImageHandle', 'Clipboard', 'Color', 'FillMode', 'Font', 'GridCell',
+    'HorizontalAlignment', 'Icon', 'Image', 'ImageHandle', 'Instant', 'Length', 'Line', 'Message',
+    'Point', 'Rectangle', 'SliderHandle', 'SliderHandleShape', 'SvgHandle', 'TextInputCursor',
+    'TextSpan', 'TooltipPosition', 'VerticalAlignment',
 
     # styles
     'ButtonStyle', 'ButtonStyleSheet', 'CheckboxStyle', 'CheckboxStyleSheet', 'ContainerStyleSheet',
-    'PaneGridStyleSheet', 'PickListMenu', 'PickListStyle', 'PickListStyleSheet',
-    'ProgressBarStyleSheet', 'RadioStyle', 'RadioStyleSheet', 'RuleStyleSheet',
-    'ScrollableStyleSheet', 'ScrollbarStyle', 'ScrollerStyle', 'Size', 'SliderStyle',
-    'SliderStyleSheet', 'TextInputStyle', 'TextInputStyleSheet',
+    'ListBoxStyle', 'MarkdownTheme', 'PaneGridStyleSheet', 'PickListMenu', 'PickListStyle',
+    'PickListStyleSheet', 'ProgressBarStyleSheet', 'RadioStyle', 'RadioStyleSheet',
+    'RuleStyleSheet', 'ScrollableStyleSheet', 'ScrollbarStyle', 'ScrollerStyle', 'Size',
+    'SliderStyle', 'SliderStyleSheet', 'TextInputStyle', 'TextInputStyleSheet', 'TogglerStyle',
+    'TogglerStyleSheet',
 
     # subscription
     'every', 'stream', 'Subscription',
@@ -486,6 +492,54 @@ class Clipboard:
         '''Writes the given text contents to the clipboard.'''
 
 
+###################################################################################################
+### AnimatedImage #################################################################################
+###################################################################################################
+
+
+class AnimatedImageHandle:
+    '''An animated_image() handle.'''
+
+    @staticmethod
+    def from_path(path: Path) -> AnimatedImageHandle:
+        '''Decodes all frames of an image file.'''
+
+    @staticmethod
+    def from_memory(bytes: BytesLike) -> AnimatedImageHandle:
+        '''Decodes all frames of the data of an image file.'''
+
+    def restart(self) -> None:
+        '''Rewinds the animation to its first frame.'''
+
+    @property
+    def frame_count(self) -> int:
+        '''The number of frames in the animation.'''
+
+    @property
+    def duration(self) -> float:
+        '''The length of one pass through the animation in seconds.'''
+
+    @property
+    def width(self) -> int:
+        '''The width of the image in pixels.'''
+
+    @property
+    def height(self) -> int:
+        '''The height of the image in pixels.'''
+
+
+def animated_image(
+    handle: AnimatedImageHandle,
+    *,
+    token: Optional[object] = None,
+    playing: bool = True,
+    loop: bool = True,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+) -> Element:
+    '''A frame that displays an animated image while keeping aspect ratio.'''
+
+
 ###################################################################################################
 ### Button ########################################################################################
 ###################################################################################################
@@ -676,6 +730,41 @@ def checkbox(
     '''A box that can be checked.'''
 
 
+###################################################################################################
+### ColorPicker ###################################################################################
+###################################################################################################
+
+
+@final
+class ColorPickerState:
+    '''The state of a color_picker().'''
+
+    @property
+    def is_focused(self) -> bool:
+        '''Whether the user is currently typing into the text field.'''
+
+    @property
+    def is_dragging(self) -> bool:
+        '''Whether the user is currently dragging a handle.'''
+
+
+def color_picker(
+    token: object,
+    state: ColorPickerState,
+    color: Color,
+    *,
+    swatches: Optional[Iterable[Color]] = None,
+    format: Literal['hex', 'rgb', 'hsv'] = 'hex',
+    alpha: bool = True,
+    size: Optional[U16] = None,
+    padding: Optional[U16] = None,
+    text_size: Optional[U16] = None,
+    font: Optional[Font] = None,
+    style: Optional[TextInputStyleSheet] = None,
+) -> Element:
+    '''A widget to choose a color.'''
+
+
 ###################################################################################################
 ### Column ########################################################################################
 ###################################################################################################
@@ -695,6 +784,42 @@ def column(
     '''A container that distributes its contents vertically.'''
 
 
+###################################################################################################
+### ComboBox ######################################################################################
+###################################################################################################
+
+
+@final
+class ComboBoxState:
+    '''The state of a combo_box().'''
+
+    @property
+    def is_open(self) -> bool:
+        '''Whether the list of matching options is currently shown.'''
+
+    @property
+    def query(self) -> Optional[str]:
+        '''The text the user has typed to filter the options.'''
+
+
+def combo_box(
+    token: object,
+    state: ComboBoxState,
+    options: Iterable[Optional[str]],
+    selected: Optional[str] = None,
+    placeholder: Optional[str] = "",
+    *,
+    matching: Literal['prefix', 'substring', 'fuzzy'] = 'substring',
+    font: Optional[Font] = None,
+    width: Optional[Length] = None,
+    padding: Optional[U16] = None,
+    size: Optional[U16] = None,
+    style: Optional[TextInputStyleSheet] = None,
+    menu_style: Optional[PickListMenu] = None,
+) -> Element:
+    '''A text input to search in a list of options, with a drop down menu of the matching options.'''
+
+
 ###################################################################################################
 ### Container #####################################################################################
 ###################################################################################################
@@ -734,6 +859,90 @@ def container(
     '''An element decorating some content.'''
 
 
+###################################################################################################
+### DatePicker ####################################################################################
+###################################################################################################
+
+
+@final
+class DatePickerState:
+    '''The state of a date_picker().'''
+
+    @property
+    def is_open(self) -> bool:
+        '''Whether the calendar is currently shown.'''
+
+
+def date_picker(
+    token: object,
+    state: DatePickerState,
+    date: Optional[date],
+    *,
+    min: Optional[date] = None,
+    max: Optional[date] = None,
+    first_weekday: Optional[int] = None,
+    format: Optional[str] = None,
+    placeholder: Optional[str] = "",
+    weekday_names: Optional[Sequence[str]] = None,
+    month_names: Optional[Sequence[str]] = None,
+    width: Optional[Length] = None,
+    padding: Optional[U16] = None,
+    text_size: Optional[U16] = None,
+    font: Optional[Font] = None,
+    style: Optional[PickListStyleSheet] = None,
+) -> Element:
+    '''A field to select a date in a calendar.'''
+
+
+###################################################################################################
+### External ######################################################################################
+###################################################################################################
+
+
+def external(
+    factory: object,
+) -> Element:
+    '''A widget that is implemented in a third-party extension module.'''
+
+
+###################################################################################################
+### Grid ##########################################################################################
+###################################################################################################
+
+
+@final
+class GridCell:
+    '''The placement of an element inside a grid().'''
+
+    def __init__(
+        self,
+        content: Element,
+        row: U16,
+        column: U16,
+        *,
+        row_span: U16 = 1,
+        column_span: U16 = 1,
+        align_x: Optional[Union[Align, HorizontalAlignment]] = None,
+        align_y: Optional[Union[Align, VerticalAlignment]] = None,
+    ) -> None:
+        ...
+
+
+def grid(
+    children: Iterable[Optional[Union[Element, GridCell]]],
+    *,
+    columns: Optional[Iterable[Length]] = None,
+    rows: Optional[Iterable[Length]] = None,
+    spacing: Optional[U16] = None,
+    padding: Optional[U16] = None,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+    align_x: Optional[Union[Align, HorizontalAlignment]] = None,
+    align_y: Optional[Union[Align, VerticalAlignment]] = None,
+) -> Element:
+    '''A container that distributes its contents in rows and columns.'''
+
+
 ###################################################################################################
 ### Icon ##########################################################################################
 ###################################################################################################
@@ -741,12 +950,28 @@ def container(
 
 @final
 class Icon:
-    '''TODO'''
+    '''The icon of a window.'''
 
-    def __init__(self, path: Path) -> None:
-        '''TODO'''
+    def __init__(self, path: Path, *, size: Optional[int] = None) -> None:
+        '''Loads an icon from a PNG, ICO or BMP file.'''
         ...
 
+    @staticmethod
+    def from_bytes(data: BytesLike, *, size: Optional[int] = None) -> Icon:
+        '''Decodes an icon from the data of an image file.'''
+
+    @staticmethod
+    def from_rgba(width: U32, height: U32, buffer: BytesLike) -> Icon:
+        '''Creates an icon from decoded pixel data.'''
+
+    @staticmethod
+    def from_handle(handle: ImageHandle, *, size: Optional[int] = None) -> Icon:
+        '''Creates an icon from an image handle.'''
+
+    @staticmethod
+    def from_svg(handle: SvgHandle, size: int) -> Icon:
+        '''Renders an SVG into a square icon.'''
+
 
 ###################################################################################################
 ### Image #########################################################################################
@@ -765,16 +990,271 @@ class ImageHandle:
     def from_memory(bytes: BytesLike) -> ImageHandle:
         '''Creates an image handle containing the image data directly.'''
 
+    @staticmethod
+    def from_pixels(
+        width: U32,
+        height: U32,
+        buffer: BytesLike,
+        format: Literal['rgba8', 'bgra8', 'rgb8', 'gray8'] = 'rgba8',
+    ) -> ImageHandle:
+        '''Creates an image handle from decoded pixel data.'''
+
+
+class Image:
+    '''A decoded image.'''
+
+    @staticmethod
+    def open(path: Path) -> Image:
+        '''Decodes an image file.'''
+
+    @staticmethod
+    def from_bytes(data: BytesLike) -> Image:
+        '''Decodes the data of an image file.'''
+
+    @staticmethod
+    def open_async(path: Path) -> Awaitable[Image]:
+        '''Same as Image.open(), but runs in the default executor of the running event loop.'''
+
+    @staticmethod
+    def from_bytes_async(data: BytesLike) -> Awaitable[Image]:
+        '''Same as Image.from_bytes(), but runs in the default executor of the running event loop.'''
+
+    @property
+    def width(self) -> int:
+        '''The width of the image in pixels.'''
+
+    @property
+    def height(self) -> int:
+        '''The height of the image in pixels.'''
+
+    @property
+    def format(self) -> Optional[str]:
+        '''The file format the image was decoded from.'''
+
+    @property
+    def color_type(self) -> str:
+        '''The layout of the pixels.'''
+
+    def resize(
+        self,
+        width: U32,
+        height: U32,
+        *,
+        filter: Literal['nearest', 'linear', 'cubic', 'gaussian', 'lanczos3'] = 'linear',
+        keep_aspect: bool = True,
+    ) -> Image:
+        '''Scales the image.'''
+
+    def thumbnail(self, max_width: U32, max_height: U32) -> Image:
+        '''Scales the image down quickly, keeping its aspect ratio.'''
+
+    def crop(self, x: U32, y: U32, width: U32, height: U32) -> Image:
+        '''Cuts out a part of the image.'''
+
+    def rotate(self, degrees: Literal[0, 90, 180, 270]) -> Image:
+        '''Rotates the image clockwise.'''
+
+    def flip(self, direction: Literal['horizontal', 'vertical']) -> Image:
+        '''Mirrors the image.'''
+
+    def to_handle(self) -> ImageHandle:
+        '''Converts the image into a handle that can be displayed with image().'''
+
 
 def image(
     handle: ImageHandle,
     *,
     width: Optional[Length] = None,
     heigth: Optional[Length] = None,
+    content_fit: Optional[Literal['contain', 'cover', 'fill', 'none', 'scale_down']] = None,
+    filter: Optional[Literal['linear', 'nearest']] = None,
+    opacity: Optional[float] = None,
+    rotation: Optional[float] = None,
 ) -> Element:
     '''A frame that displays an image while keeping aspect ratio.'''
 
 
+###################################################################################################
+### ImageViewer ###################################################################################
+###################################################################################################
+
+
+@final
+class ImageViewerState:
+    '''The state of an image_viewer().'''
+
+    def __init__(self) -> None:
+        ...
+
+    @property
+    def scale(self) -> float:
+        '''The current zoom factor, 1.0 shows the image in its natural size.'''
+
+    @property
+    def offset(self) -> Point:
+        '''The position of the top-left corner of the image, relative to the top-left corner of the viewer.'''
+
+    def fit(self) -> None:
+        '''Scale and center the image, so that it is completely visible.'''
+
+    def zoom_to(self, rect: Rectangle) -> None:
+        '''Scale and center the image, so that the area is completely visible.'''
+
+
+def image_viewer(
+    state: ImageViewerState,
+    handle: ImageHandle,
+    *,
+    min_scale: Optional[float] = 0.25,
+    max_scale: Optional[float] = 10.0,
+    scale_step: Optional[float] = 0.1,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+) -> Element:
+    '''A frame that displays an image, which can be zoomed and panned.'''
+
+
+###################################################################################################
+### ListBox #######################################################################################
+###################################################################################################
+
+
+@final
+class ListBoxState:
+    '''The state of a list_box().'''
+
+    @property
+    def is_focused(self) -> bool:
+        '''Whether the list box has the keyboard focus.'''
+
+    @property
+    def cursor(self) -> Optional[int]:
+        '''The index of the current item for keyboard navigation.'''
+
+
+@final
+class ListBoxStyle:
+    '''The appearance of a list_box().'''
+
+    def __init__(
+        self,
+        proto : Optional[ListBoxStyle] = ...,
+        *,
+        background : Color = ...,
+        border_radius : FloatFinite = ...,
+        border_width : FloatFinite = ...,
+        border_color : Color = ...,
+        text_color : Color = ...,
+        hovered_text_color : Color = ...,
+        hovered_background : Color = ...,
+        selected_text_color : Color = ...,
+        selected_background : Color = ...,
+        cursor_color : Color = ...,
+    ) -> None:
+        ...
+
+    @property
+    def background(self) -> Color:
+        '''The (set, copied or defaulted) 'background' parameter given to the constructor.'''
+
+    @property
+    def border_radius(self) -> FloatFinite:
+        '''The (set, copied or defaulted) 'border_radius' parameter given to the constructor.'''
+
+    @property
+    def border_width(self) -> FloatFinite:
+        '''The (set, copied or defaulted) 'border_width' parameter given to the constructor.'''
+
+    @property
+    def border_color(self) -> Color:
+        '''The (set, copied or defaulted) 'border_color' parameter given to the constructor.'''
+
+    @property
+    def text_color(self) -> Color:
+        '''The (set, copied or defaulted) 'text_color' parameter given to the constructor.'''
+
+    @property
+    def hovered_text_color(self) -> Color:
+        '''The (set, copied or defaulted) 'hovered_text_color' parameter given to the constructor.'''
+
+    @property
+    def hovered_background(self) -> Color:
+        '''The (set, copied or defaulted) 'hovered_background' parameter given to the constructor.'''
+
+    @property
+    def selected_text_color(self) -> Color:
+        '''The (set, copied or defaulted) 'selected_text_color' parameter given to the constructor.'''
+
+    @property
+    def selected_background(self) -> Color:
+        '''The (set, copied or defaulted) 'selected_background' parameter given to the constructor.'''
+
+    @property
+    def cursor_color(self) -> Color:
+        '''The (set, copied or defaulted) 'cursor_color' parameter given to the constructor.'''
+
+
+def list_box(
+    token: object,
+    state: ListBoxState,
+    items: Iterable[object],
+    selected: Optional[Container[object]] = None,
+    *,
+    mode: Literal['single', 'multi', 'extended'] = 'single',
+    label: Optional[Callable[[object], str]] = None,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+    padding: Optional[U16] = None,
+    text_size: Optional[U16] = None,
+    font: Optional[Font] = None,
+    style: Optional[ListBoxStyle] = None,
+    scrollbar_style: Optional[ScrollableStyleSheet] = None,
+) -> Element:
+    '''A scrollable list of items, of which one or many can be selected.'''
+
+
+###################################################################################################
+### Markdown ######################################################################################
+###################################################################################################
+
+
+@final
+class MarkdownTheme:
+    '''The appearance of a markdown().'''
+
+    def __init__(
+        proto : Optional[MarkdownTheme] = None,
+        *,
+        text_size : U16 = ...,
+        text_color : Optional[Color] = ...,
+        font : Optional[Font] = ...,
+        emphasis_font : Optional[Font] = ...,
+        strong_font : Optional[Font] = ...,
+        heading_font : Optional[Font] = ...,
+        heading_sizes : Tuple[U16, U16, U16, U16, U16, U16] = ...,
+        heading_color : Optional[Color] = ...,
+        code_font : Optional[Font] = ...,
+        code_size : U16 = ...,
+        code_color : Optional[Color] = ...,
+        code_background : Optional[Color] = ...,
+        link_color : Optional[Color] = ...,
+        quote_color : Optional[Color] = ...,
+        spacing : U16 = ...,
+    ) -> None:
+        ...
+
+
+def markdown(
+    source: str,
+    *,
+    on_link: Optional[object] = None,
+    theme: Optional[MarkdownTheme] = None,
+    width: Optional[Length] = None,
+    padding: Optional[U16] = None,
+) -> Element:
+    '''Render a CommonMark document.'''
+
+
 ###################################################################################################
 ### no_element ####################################################################################
 ###################################################################################################
@@ -784,6 +1264,39 @@ def no_element() -> Element:
     '''A space() with minimum width and height.'''
 
 
+###################################################################################################
+### NumberInput ###################################################################################
+###################################################################################################
+
+
+@final
+class NumberInputState:
+    '''The state of a number_input().'''
+
+    @property
+    def is_focused(self) -> bool:
+        '''Whether the user is currently typing into the input.'''
+
+
+def number_input(
+    token: object,
+    state: NumberInputState,
+    value: Union[int, float],
+    *,
+    min: Optional[float] = None,
+    max: Optional[float] = None,
+    step: Optional[float] = 1,
+    decimals: Optional[int] = None,
+    placeholder: Optional[str] = "",
+    font: Optional[Font] = None,
+    width: Optional[Length] = None,
+    padding: Optional[U16] = None,
+    size: Optional[U16] = None,
+    style: Optional[TextInputStyleSheet] = None,
+) -> Element:
+    '''A field to enter a number, with buttons to increment and decrement it.'''
+
+
 ###################################################################################################
 ### PickList ######################################################################################
 ###################################################################################################
@@ -911,9 +1424,10 @@ class PickListStyleSheet:
 def pick_list(
     token: object,
     state: PickListState,
-    selected: Optional[str],
-    options: Iterable[Optional[str]],
+    selected: Optional[object],
+    options: Iterable[Optional[object]],
     *,
+    label: Optional[Callable[[object], str]] = None,
     text_size: Optional[U16],
     font: Optional[Font],
     style: Optional[PickListStyleSheet],
@@ -1041,6 +1555,42 @@ def radio(
     '''A circular button representing a choice.'''
 
 
+###################################################################################################
+### RichText ######################################################################################
+###################################################################################################
+
+
+@final
+class TextSpan:
+    '''A styled run of text inside a rich_text().'''
+
+
+def span(
+    text: str,
+    *,
+    color: Optional[Color] = None,
+    font: Optional[Font] = None,
+    size: Optional[U16] = None,
+    underline: bool = False,
+    link: Optional[object] = None,
+) -> TextSpan:
+    '''A styled run of text inside a rich_text().'''
+
+
+def rich_text(
+    spans: Iterable[Optional[Union[str, TextSpan]]],
+    *,
+    token: Optional[object] = None,
+    size: Optional[U16] = None,
+    color: Optional[Color] = None,
+    font: Optional[Font] = None,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+    horizontal_alignment: Optional[HorizontalAlignment] = None,
+) -> Element:
+    '''A paragraph of text with mixed styles.'''
+
+
 ###################################################################################################
 ### Row ###########################################################################################
 ###################################################################################################
@@ -1276,6 +1826,25 @@ def scrollable(
 
 
 
+###################################################################################################
+### SelectableText ################################################################################
+###################################################################################################
+
+
+def selectable_text(
+    state: TextInputState,
+    value: str,
+    *,
+    font: Optional[Font] = None,
+    width: Optional[Length] = None,
+    max_width: Optional[U32] = None,
+    padding: Optional[U16] = None,
+    size: Optional[U16] = None,
+    style: Optional[TextInputStyleSheet] = None,
+) -> Element:
+    '''A single line of text that the user can select and copy, but not change.'''
+
+
 ###################################################################################################
 ### Slider ########################################################################################
 ###################################################################################################
@@ -1283,7 +1852,15 @@ def scrollable(
 
 @final
 class SliderState:
-    '''The state of a slider().'''
+    '''The state of a slider() or range_slider().'''
+
+    @property
+    def is_dragging(self) -> bool:
+        '''Whether the user is currently dragging a handle.'''
+
+    @property
+    def is_focused(self) -> bool:
+        '''Whether the slider has the keyboard focus.'''
 
 
 @final
@@ -1396,10 +1973,33 @@ def slider(
     step: FloatPositive = 1.0,
     *,
     width: Optional[Length] = None,
-    height: Optional[U16] = None,
+    height: Union[U16, Length, None] = None,
+    orientation: Literal['horizontal', 'vertical'] = 'horizontal',
+    release_only: bool = False,
+    on_drag_start: Optional[object] = None,
+    on_release: Optional[object] = None,
+    style: Optional[SliderStyleSheet] = None,
+) -> Element:
+    '''A bar and a handle that selects a single value from a range of values.'''
+
+
+def range_slider(
+    token: object,
+    state: SliderState,
+    start: FloatFinite,
+    end: FloatFinite,
+    value: Tuple[FloatFinite, FloatFinite],
+    step: FloatPositive = 1.0,
+    *,
+    width: Optional[Length] = None,
+    height: Union[U16, Length, None] = None,
+    orientation: Literal['horizontal', 'vertical'] = 'horizontal',
+    release_only: bool = False,
+    on_drag_start: Optional[object] = None,
+    on_release: Optional[object] = None,
     style: Optional[SliderStyleSheet] = None,
 ) -> Element:
-    '''An horizontal bar and a handle that selects a single value from a range of values.'''
+    '''A bar and two handles that select a range of values.'''
 
 
 ###################################################################################################
@@ -1415,6 +2015,26 @@ def space(
     '''An amount of empty space.'''
 
 
+###################################################################################################
+### Stack #########################################################################################
+###################################################################################################
+
+
+def stack(
+    children: Iterable[Optional[Union[
+        Element,
+        Tuple[Element, Optional[Align], Optional[Align]],
+        Tuple[Element, Optional[Align], Optional[Align], Optional[Tuple[FloatFinite, FloatFinite]]],
+    ]]],
+    *,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+    align_x: Optional[Align] = None,
+    align_y: Optional[Align] = None,
+) -> Element:
+    '''A container that places all its children on top of each other.'''
+
+
 ###################################################################################################
 ### Svg ###########################################################################################
 ###################################################################################################
@@ -1432,12 +2052,24 @@ class SvgHandle:
     def from_memory(bytes: BytesLike) -> SvgHandle:
         '''Creates an SVG handle containing the image data directly.'''
 
+    @staticmethod
+    def from_template(source: str, variables: dict[str, Union[Color, str, float]]) -> SvgHandle:
+        '''Creates an SVG handle from a template, substituting ``${name}`` placeholders.'''
+
+    def rasterize(self, width: int, height: int) -> ImageHandle:
+        '''Renders the SVG into a pixel image.'''
+
 
 def svg(
     handle: SvgHandle,
     *,
     width: Optional[Length] = None,
     heigth: Optional[Length] = None,
+    content_fit: Optional[Literal['contain', 'cover', 'fill', 'none', 'scale_down']] = None,
+    filter: Optional[Literal['linear', 'nearest']] = None,
+    opacity: Optional[float] = None,
+    rotation: Optional[float] = None,
+    color: Optional[Color] = None,
 ) -> Element:
     '''A vector graphics image.'''
 
@@ -1457,6 +2089,10 @@ def text(
     height: Optional[Length] = None,
     horizontal_alignment: Optional[HorizontalAlignment] = None,
     vertical_alignment: Optional[VerticalAlignment] = None,
+    max_lines: Optional[Annotated[int, _Positive()]] = None,
+    wrap: Optional[Literal['word', 'char', 'none']] = None,
+    ellipsis: Optional[Literal['end', 'middle', 'start']] = None,
+    tooltip: Optional[TooltipPosition] = None,
 ) -> Element:
     '''A paragraph of text.'''
 
@@ -1469,6 +2105,121 @@ def text(
 # TODO
 
 
+###################################################################################################
+### TimePicker ####################################################################################
+###################################################################################################
+
+
+@final
+class TimePickerState:
+    '''The state of a time_picker().'''
+
+    @property
+    def is_focused(self) -> bool:
+        '''Whether the time picker has the keyboard focus.'''
+
+
+def time_picker(
+    token: object,
+    state: TimePickerState,
+    time: Optional[time],
+    *,
+    show_seconds: bool = False,
+    width: Optional[Length] = None,
+    padding: Optional[U16] = None,
+    text_size: Optional[U16] = None,
+    font: Optional[Font] = None,
+    style: Optional[TextInputStyleSheet] = None,
+) -> Element:
+    '''A field to enter a time of the day.'''
+
+
+###################################################################################################
+### Toggler #######################################################################################
+###################################################################################################
+
+
+@final
+class TogglerStyle:
+    '''The appearance of a toggler() for some state.'''
+
+    def __init__(
+        self,
+        proto: Optional[Union[
+            TogglerStyle,
+            Literal['active', 'hovered', 'active_on', 'hovered_on'],
+        ]] = 'active',
+        *,
+        background: Color = ...,
+        background_border: Optional[Color] = ...,
+        foreground: Color = ...,
+        foreground_border: Optional[Color] = ...,
+    ) -> None:
+        ...
+
+    @property
+    def background(self) -> Color:
+        '''The (set, copied or defaulted) 'background' parameter given to the constructor.'''
+
+    @property
+    def background_border(self) -> Optional[Color]:
+        '''The (set, copied or defaulted) 'background_border' parameter given to the constructor.'''
+
+    @property
+    def foreground(self) -> Color:
+        '''The (set, copied or defaulted) 'foreground' parameter given to the constructor.'''
+
+    @property
+    def foreground_border(self) -> Optional[Color]:
+        '''The (set, copied or defaulted) 'foreground_border' parameter given to the constructor.'''
+
+
+@final
+class TogglerStyleSheet:
+    '''The appearance of a toggler().'''
+
+    def __init__(
+        self,
+        active: TogglerStyle,
+        hovered: Optional[TogglerStyle] = None,
+        active_on: Optional[TogglerStyle] = None,
+        hovered_on: Optional[TogglerStyle] = None,
+    ) -> None:
+        ...
+
+    @property
+    def active(self) -> TogglerStyle:
+        '''The (set, copied or defaulted) 'active' parameter given to the constructor.'''
+
+    @property
+    def hovered(self) -> TogglerStyle:
+        '''The (set, copied or defaulted) 'hovered' parameter given to the constructor.'''
+
+    @property
+    def active_on(self) -> TogglerStyle:
+        '''The (set, copied or defaulted) 'active_on' parameter given to the constructor.'''
+
+    @property
+    def hovered_on(self) -> TogglerStyle:
+        '''The (set, copied or defaulted) 'hovered_on' parameter given to the constructor.'''
+
+
+def toggler(
+    token: object,
+    is_active: bool,
+    label: Optional[str] = None,
+    *,
+    size: Optional[U16] = None,
+    width: Optional[Length] = None,
+    spacing: Optional[U16] = None,
+    text_size: Optional[U16] = None,
+    text_alignment: Optional[HorizontalAlignment] = None,
+    font: Optional[Font] = None,
+    style: Optional[TogglerStyleSheet] = None,
+) -> Element:
+    '''A switch that can be toggled on and off.'''
+
+
 ###################################################################################################
 ### Tooltip #######################################################################################
 ###################################################################################################
@@ -1506,3 +2257,19 @@ def tooltip(
     style: Optional[ContainerStyleSheet] = None,
 ) -> Element:
     '''Make a tooltip.'''
+###################################################################################################
+### Wrap ##########################################################################################
+###################################################################################################
+
+
+def wrap(
+    children: Iterable[Optional[Element]],
+    *,
+    spacing: Optional[U16] = None,
+    line_spacing: Optional[U16] = None,
+    padding: Optional[U16] = None,
+    width: Optional[Length] = None,
+    height: Optional[Length] = None,
+    align_items: Optional[Align] = None,
+) -> Element:
+    '''A container that distributes its contents horizontally, and wraps into new lines.'''
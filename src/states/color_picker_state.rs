@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use iced::{text_input, Color};
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedColorPickerState>()?;
+    Ok(())
+}
+
+pub(crate) type ColorPickerState = Arc<RwLock<ColorState>>;
+
+/// A part of the color picker that can be dragged with the mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColorArea {
+    /// The saturation/value square.
+    Square,
+    Hue,
+    Alpha,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ColorState {
+    pub input: text_input::State,
+    /// The text while the user is typing, or None to show the formatted color.
+    pub editing: Option<String>,
+    /// Whether the text part has the focus, tracked like the text input does it.
+    pub focused: bool,
+    pub dragging: Option<ColorArea>,
+    /// The last sent color, and its hue, saturation and value.
+    /// The hue cannot be derived from a gray, and the saturation not from black.
+    pub hsv: Option<(Color, [f32; 3])>,
+}
+
+/// ColorPickerState()
+/// --
+///
+/// The state of a :func:`~pyiced.color_picker()`.
+#[pyclass(name = "ColorPickerState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedColorPickerState(pub ColorPickerState);
+
+#[pymethods]
+impl WrappedColorPickerState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the user is currently typing into the text field.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the text part is focused.
+    #[getter]
+    fn is_focused(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.focused),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// Whether the user is currently dragging a handle.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True while the mouse button is held down on the square or on a strip.
+    #[getter]
+    fn is_dragging(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.dragging.is_some()),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
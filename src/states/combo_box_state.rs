@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use iced::text_input;
+use iced_native::overlay::menu;
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedComboBoxState>()?;
+    Ok(())
+}
+
+pub(crate) type ComboBoxState = Arc<RwLock<ComboState>>;
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ComboState {
+    pub input: text_input::State,
+    pub menu: menu::State,
+    /// The text while the user is typing, or None to show the selected value.
+    pub query: Option<String>,
+    /// Whether the text part has the focus, tracked like the text input does it.
+    pub focused: bool,
+    pub is_open: bool,
+    pub hovered_option: Option<usize>,
+    pub last_selection: Option<String>,
+}
+
+/// ComboBoxState()
+/// --
+///
+/// The state of a :func:`~pyiced.combo_box()`.
+#[pyclass(name = "ComboBoxState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedComboBoxState(pub ComboBoxState);
+
+#[pymethods]
+impl WrappedComboBoxState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the list of matching options is currently shown.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the drop down menu is open.
+    #[getter]
+    fn is_open(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.is_open),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// The text the user has typed to filter the options.
+    ///
+    /// Returns
+    /// -------
+    /// Optional[str]
+    ///     None if the user is not currently searching.
+    #[getter]
+    fn query(&self) -> PyResult<Option<String>> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.query.clone()),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedDatePickerState>()?;
+    Ok(())
+}
+
+pub(crate) type DatePickerState = Arc<RwLock<DateState>>;
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DateState {
+    pub is_open: bool,
+    /// The year and month shown in the calendar.
+    pub shown: (i32, u8),
+}
+
+/// DatePickerState()
+/// --
+///
+/// The state of a :func:`~pyiced.date_picker()`.
+#[pyclass(name = "DatePickerState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedDatePickerState(pub DatePickerState);
+
+#[pymethods]
+impl WrappedDatePickerState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the calendar is currently shown.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the calendar is open.
+    #[getter]
+    fn is_open(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.is_open),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
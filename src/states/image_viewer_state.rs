@@ -0,0 +1,229 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use iced::{Point, Rectangle, Size, Vector};
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+use crate::wrapped::{WrappedPoint, WrappedRectangle};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedImageViewerState>()?;
+    Ok(())
+}
+
+pub(crate) type ImageViewerState = Arc<RwLock<ViewerState>>;
+
+#[derive(Debug, Clone)]
+pub(crate) struct ViewerState {
+    /// Zoom factor, 1.0 shows the image in its natural size.
+    pub scale: f32,
+    /// Position of the top-left corner of the image, relative to the viewer.
+    pub offset: Vector,
+    /// Size of the viewer, when it was drawn last.
+    pub viewport: Option<Size>,
+    /// Size of the image, when it was drawn last.
+    pub image: Option<Size>,
+    /// Scale limits, when it was drawn last.
+    pub min_scale: f32,
+    pub max_scale: f32,
+    /// Operation to apply once the viewport and image sizes are known.
+    pub pending: Option<Pending>,
+    /// Cursor position and offset when the drag started.
+    pub drag: Option<(Point, Vector)>,
+    /// Time and position of the last click, to detect double-clicks.
+    pub last_click: Option<(Instant, Point)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Pending {
+    Fit,
+    ZoomTo(Rectangle),
+}
+
+impl Default for ViewerState {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            offset: Vector::new(0.0, 0.0),
+            viewport: None,
+            image: None,
+            min_scale: 0.25,
+            max_scale: 10.0,
+            pending: Some(Pending::Fit),
+            drag: None,
+            last_click: None,
+        }
+    }
+}
+
+impl ViewerState {
+    /// The scale that shows the whole image.
+    fn fit_scale(&self, viewport: Size, image: Size) -> f32 {
+        let scale = (viewport.width / image.width).min(viewport.height / image.height);
+        match scale.is_finite() && scale > 0.0 {
+            true => scale.min(self.max_scale),
+            false => 1.0,
+        }
+    }
+
+    /// The smallest allowed scale. Zooming out until the whole image is visible is always allowed.
+    pub(crate) fn lowest_scale(&self) -> f32 {
+        match (self.viewport, self.image) {
+            (Some(viewport), Some(image)) => self.min_scale.min(self.fit_scale(viewport, image)),
+            _ => self.min_scale,
+        }
+    }
+
+    /// Applies a pending operation if the viewport and image sizes are known.
+    pub(crate) fn apply_pending(&mut self) {
+        let (viewport, image) = match (self.viewport, self.image) {
+            (Some(viewport), Some(image)) => (viewport, image),
+            _ => return,
+        };
+        let rect = match self.pending.take() {
+            Some(Pending::Fit) => Rectangle::with_size(image),
+            Some(Pending::ZoomTo(rect)) => rect,
+            None => return,
+        };
+
+        let scale = (viewport.width / rect.width).min(viewport.height / rect.height);
+        self.scale = match scale.is_finite() && scale > 0.0 {
+            true => scale.max(self.lowest_scale()).min(self.max_scale),
+            false => self.scale,
+        };
+        let center = rect.center();
+        self.offset = Vector::new(
+            viewport.width / 2.0 - center.x * self.scale,
+            viewport.height / 2.0 - center.y * self.scale,
+        );
+        self.clamp_offset();
+    }
+
+    /// Zooms by `factor`, keeping the image point below `anchor` (relative to the viewer) in place.
+    pub(crate) fn zoom(&mut self, factor: f32, anchor: Point) {
+        let scale = (self.scale * factor)
+            .max(self.lowest_scale())
+            .min(self.max_scale);
+        let x = (anchor.x - self.offset.x) / self.scale;
+        let y = (anchor.y - self.offset.y) / self.scale;
+        self.offset = Vector::new(anchor.x - x * scale, anchor.y - y * scale);
+        self.scale = scale;
+        self.clamp_offset();
+    }
+
+    /// Keeps at least half of the viewport covered by the image in each dimension, if possible.
+    pub(crate) fn clamp_offset(&mut self) {
+        let (viewport, image) = match (self.viewport, self.image) {
+            (Some(viewport), Some(image)) => (viewport, image),
+            _ => return,
+        };
+        let clamp = |offset: f32, viewport: f32, image: f32| {
+            let image = image * self.scale;
+            let (low, high) = (viewport / 2.0 - image, viewport / 2.0);
+            match image < viewport {
+                true => (viewport - image) / 2.0,
+                false => offset.max(low).min(high),
+            }
+        };
+        self.offset = Vector::new(
+            clamp(self.offset.x, viewport.width, image.width),
+            clamp(self.offset.y, viewport.height, image.height),
+        );
+    }
+}
+
+/// ImageViewerState()
+/// --
+///
+/// The state of an :func:`~pyiced.image_viewer()`.
+///
+/// Initially the image is fitted into the viewer.
+///
+/// Warning
+/// -------
+/// If the state is currently in use, calling its methods will fail.
+#[pyclass(name = "ImageViewerState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedImageViewerState(pub ImageViewerState);
+
+#[pymethods]
+impl WrappedImageViewerState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current zoom factor, 1.0 shows the image in its natural size.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The zoom factor.
+    #[getter]
+    fn scale(&self) -> PyResult<f32> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.scale),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// The position of the top-left corner of the image, relative to the top-left corner of the viewer.
+    ///
+    /// Returns
+    /// -------
+    /// Point
+    ///     The offset.
+    #[getter]
+    fn offset(&self) -> PyResult<WrappedPoint> {
+        match self.0.try_read() {
+            Some(guard) => Ok(WrappedPoint(Point::ORIGIN + guard.offset)),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// fit($self)
+    /// --
+    ///
+    /// Scale and center the image, so that it is completely visible.
+    ///
+    /// If the viewer was not drawn yet, the operation is applied when it is drawn.
+    fn fit(&self) -> PyResult<()> {
+        match self.0.try_write() {
+            Some(mut guard) => {
+                guard.pending = Some(Pending::Fit);
+                guard.apply_pending();
+                Ok(())
+            },
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// zoom_to($self, /, rect)
+    /// --
+    ///
+    /// Scale and center the image, so that the area is completely visible.
+    ///
+    /// If the viewer was not drawn yet, the operation is applied when it is drawn.
+    ///
+    /// Arguments
+    /// ---------
+    /// rect : Rectangle
+    ///     The area to show in image pixel coordinates.
+    fn zoom_to(&self, rect: &WrappedRectangle) -> PyResult<()> {
+        match self.0.try_write() {
+            Some(mut guard) => {
+                guard.pending = Some(Pending::ZoomTo(rect.0));
+                guard.apply_pending();
+                Ok(())
+            },
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
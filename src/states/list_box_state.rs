@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use iced_native::keyboard::Modifiers;
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedListBoxState>()?;
+    Ok(())
+}
+
+pub(crate) type ListBoxState = Arc<RwLock<ListState>>;
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ListState {
+    /// Scroll offset in pixels.
+    pub offset: f32,
+    /// Where the scroller was grabbed, relative to its top, while it is being dragged.
+    pub grabbed_at: Option<f32>,
+    pub focused: bool,
+    /// The current item for keyboard navigation.
+    pub cursor: Option<usize>,
+    /// The start of a shift-click range.
+    pub anchor: Option<usize>,
+    pub modifiers: Modifiers,
+    /// The typed prefix of the type-ahead search, and when it was last typed into.
+    pub search: String,
+    pub searched_at: Option<Instant>,
+}
+
+/// ListBoxState()
+/// --
+///
+/// The state of a :func:`~pyiced.list_box()`.
+#[pyclass(name = "ListBoxState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedListBoxState(pub ListBoxState);
+
+#[pymethods]
+impl WrappedListBoxState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the list box has the keyboard focus.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the list box was clicked last.
+    #[getter]
+    fn is_focused(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.focused),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// The index of the current item for keyboard navigation.
+    ///
+    /// Returns
+    /// -------
+    /// Optional[int]
+    ///     None if the user has not clicked on an item yet.
+    #[getter]
+    fn cursor(&self) -> PyResult<Option<usize>> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.cursor),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
@@ -18,8 +18,17 @@ macro_rules! init_mod {
 
 init_mod! {
     button_state -> { ButtonState, WrappedButtonState, button_with_state },
-    pick_list_state -> { PickListState, WrappedPickListState, pick_list_with_state },
+    color_picker_state -> { ColorArea, ColorPickerState, ColorState, WrappedColorPickerState },
+    combo_box_state -> { ComboBoxState, ComboState, WrappedComboBoxState },
+    date_picker_state -> { DatePickerState, DateState, WrappedDatePickerState },
+    image_viewer_state -> { ImageViewerState, Pending, ViewerState, WrappedImageViewerState },
+    list_box_state -> { ListBoxState, ListState, WrappedListBoxState },
+    number_input_state -> { NumberInputState, NumberState, WrappedNumberInputState },
+    pick_list_state -> {
+        PickListOption, PickListState, WrappedPickListState, pick_list_with_state
+    },
     scrollable_state -> { ScrollableState, WrappedScrollableState, scrollable_with_state },
-    slider_state -> { SliderState, WrappedSliderState, slider_with_state },
+    slider_state -> { SlideState, SliderState, WrappedSliderState },
     text_input_state -> { TextInputState, WrappedTextInputState, text_input_with_state },
+    time_picker_state -> { TimePickerState, TimeState, WrappedTimePickerState },
 }
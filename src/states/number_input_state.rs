@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use iced::text_input;
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedNumberInputState>()?;
+    Ok(())
+}
+
+pub(crate) type NumberInputState = Arc<RwLock<NumberState>>;
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct NumberState {
+    pub input: text_input::State,
+    /// The text while the user is typing, or None to show the formatted value.
+    pub editing: Option<String>,
+    /// Whether the text part has the focus, tracked like the text input does it.
+    pub focused: bool,
+}
+
+/// NumberInputState()
+/// --
+///
+/// The state of a :func:`~pyiced.number_input()`.
+#[pyclass(name = "NumberInputState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedNumberInputState(pub NumberInputState);
+
+#[pymethods]
+impl WrappedNumberInputState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the user is currently typing into the input.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the text part is focused.
+    #[getter]
+    fn is_focused(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.focused),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
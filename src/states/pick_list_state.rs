@@ -1,3 +1,4 @@
+use std::fmt;
 use std::sync::Arc;
 
 use iced::pick_list::State;
@@ -12,7 +13,21 @@ pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     Ok(())
 }
 
-pub(crate) type PickListState = Arc<RwLock<State<String>>>;
+pub(crate) type PickListState = Arc<RwLock<State<PickListOption>>>;
+
+/// An entry of a pick list. The Python object it stands for is looked up by its index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PickListOption {
+    /// None if the selected value is not one of the options.
+    pub index: Option<usize>,
+    pub label: String,
+}
+
+impl fmt::Display for PickListOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
 
 /// PickListState()
 /// --
@@ -36,8 +51,8 @@ impl WrappedPickListState {
 
 make_with_state! {
     pick_list_with_state(
-        iced::PickList<String, Message>,
-        iced::PickList<'this, String, Message>,
-        iced::pick_list::State<String>,
+        iced::PickList<crate::states::PickListOption, Message>,
+        iced::PickList<'this, crate::states::PickListOption, Message>,
+        iced::pick_list::State<crate::states::PickListOption>,
     );
 }
@@ -1,23 +1,48 @@
 use std::sync::Arc;
 
-use iced::slider::State;
 use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
 
 use crate::common::debug_str;
-use crate::make_with_state;
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<WrappedSliderState>()?;
     Ok(())
 }
 
-pub(crate) type SliderState = Arc<RwLock<State>>;
+pub(crate) type SliderState = Arc<RwLock<SlideState>>;
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SlideState {
+    /// The dragged handle: 0 for the only or the low handle, 1 for the high handle.
+    pub dragging: Option<usize>,
+    /// The handle that is moved with the keyboard.
+    pub focused: Option<usize>,
+    /// The values while a handle is dragged, if the slider only sends them on release.
+    pub pending: Option<Vec<f32>>,
+}
+
+impl SlideState {
+    /// Forgets the handles and values that do not exist in a slider with `handles` handles,
+    /// e.g. if the state of a range_slider() is reused for a slider().
+    pub(crate) fn fit(&mut self, handles: usize) {
+        if self.dragging.is_some_and(|handle| handle >= handles) {
+            self.dragging = None;
+        }
+        if self.focused.is_some_and(|handle| handle >= handles) {
+            self.focused = None;
+        }
+        if self.pending.as_ref().is_some_and(|values| values.len() != handles) {
+            self.pending = None;
+        }
+    }
+}
 
 /// SliderState()
 /// --
 ///
-/// The state of a :func:`~pyiced.slider()`.
+/// The state of a :func:`~pyiced.slider()` or :func:`~pyiced.range_slider()`.
 #[pyclass(name = "SliderState", module = "pyiced")]
 #[derive(Debug, Default, Clone)]
 pub(crate) struct WrappedSliderState(pub SliderState);
@@ -29,15 +54,35 @@ impl WrappedSliderState {
         Self::default()
     }
 
+    /// Whether the user is currently dragging a handle.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True while the mouse button is held down on the slider.
+    #[getter]
+    fn is_dragging(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.dragging.is_some()),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    /// Whether the slider has the keyboard focus.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if the slider was clicked last.
+    #[getter]
+    fn is_focused(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.focused.is_some()),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
     fn __str__(&self) -> PyResult<String> {
         debug_str(&self.0)
     }
 }
-
-make_with_state! {
-    slider_with_state(
-        iced::Slider<f32, Message>,
-        iced::Slider<'this, f32, Message>,
-        iced::slider::State,
-    );
-}
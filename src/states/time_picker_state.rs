@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::common::debug_str;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedTimePickerState>()?;
+    Ok(())
+}
+
+pub(crate) type TimePickerState = Arc<RwLock<TimeState>>;
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TimeState {
+    /// The focused field: 0 = hours, 1 = minutes, 2 = seconds.
+    pub focused: Option<usize>,
+    /// The first digit if the user is typing a two digit number.
+    pub typed: Option<u8>,
+}
+
+/// TimePickerState()
+/// --
+///
+/// The state of a :func:`~pyiced.time_picker()`.
+#[pyclass(name = "TimePickerState", module = "pyiced")]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrappedTimePickerState(pub TimePickerState);
+
+#[pymethods]
+impl WrappedTimePickerState {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the time picker has the keyboard focus.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    ///     True if one of the fields is focused.
+    #[getter]
+    fn is_focused(&self) -> PyResult<bool> {
+        match self.0.try_read() {
+            Some(guard) => Ok(guard.focused.is_some()),
+            None => Err(PyErr::new::<PyRuntimeError, _>("State is in use")),
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
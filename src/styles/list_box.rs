@@ -0,0 +1,120 @@
+#![allow(clippy::needless_option_as_deref)]
+
+use iced::Color;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::wrapped::WrappedColor;
+use crate::{extract_multiple, getters};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedListBoxStyle>()?;
+    Ok(())
+}
+
+/// The appearance of a list box.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Style {
+    pub background: Color,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub hovered_text_color: Color,
+    pub hovered_background: Color,
+    pub selected_text_color: Color,
+    pub selected_background: Color,
+    pub cursor_color: Color,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE,
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            hovered_text_color: Color::BLACK,
+            hovered_background: [0.9, 0.9, 0.9].into(),
+            selected_text_color: Color::WHITE,
+            selected_background: [0.4, 0.4, 1.0].into(),
+            cursor_color: [0.5, 0.5, 0.5].into(),
+        }
+    }
+}
+
+/// ListBoxStyle(proto=None, **kwargs)
+/// --
+///
+/// The appearance of a :func:`~pyiced.list_box()`.
+///
+/// Parameters
+/// ----------
+/// proto : Optional[ListBoxStyle]
+///     Source style sheet to clone and modify.
+///     Defaults to the colors of `iced_style's <https://docs.rs/iced_style/0.3.0/iced_style/>`_ pick list menu.
+/// background : Color
+///     The background color of the list.
+/// border_radius : float
+///     The border radius of the list.
+/// border_width : float
+///     The border width of the list.
+/// border_color : Color
+///     The border color of the list.
+/// text_color : Color
+///     The text color of an item.
+/// hovered_text_color : Color
+///     The text color of the item under the mouse cursor.
+/// hovered_background : Color
+///     The background color of the item under the mouse cursor.
+/// selected_text_color : Color
+///     The text color of a selected item.
+/// selected_background : Color
+///     The background color of a selected item.
+/// cursor_color : Color
+///     The color of the outline around the current item, if the list has the keyboard focus.
+#[pyclass(name = "ListBoxStyle", module = "pyiced")]
+#[derive(Debug, Clone, Default, Copy)]
+pub(crate) struct WrappedListBoxStyle(pub ListBoxStyle);
+
+#[derive(Debug, Clone, Default, Copy)]
+pub(crate) struct ListBoxStyle(pub Style);
+
+#[pymethods]
+impl WrappedListBoxStyle {
+    #[args(prototype = "None", kwargs = "**")]
+    #[new]
+    fn new(proto: Option<&Self>, kwargs: Option<&PyDict>) -> PyResult<Self> {
+        let proto = proto.map_or_else(Style::default, |p| p.0.0);
+        extract_multiple!(
+            kwargs,
+            ListBoxStyle(proto),
+            background,
+            border_radius,
+            border_width,
+            border_color,
+            text_color,
+            hovered_text_color,
+            hovered_background,
+            selected_text_color,
+            selected_background,
+            cursor_color,
+        )
+    }
+}
+
+getters! {
+    WrappedListBoxStyle => |&WrappedListBoxStyle(ListBoxStyle(ref o))| o,
+    background -> "Color" WrappedColor,
+    border_radius -> "float" f32,
+    border_width -> "float" f32,
+    border_color -> "Color" WrappedColor,
+    text_color -> "Color" WrappedColor,
+    hovered_text_color -> "Color" WrappedColor,
+    hovered_background -> "Color" WrappedColor,
+    selected_text_color -> "Color" WrappedColor,
+    selected_background -> "Color" WrappedColor,
+    cursor_color -> "Color" WrappedColor,
+}
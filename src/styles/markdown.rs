@@ -0,0 +1,169 @@
+use iced::{Color, Font};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::wrapped::{WrappedColor, WrappedFont};
+use crate::{extract_multiple, getters};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedMarkdownTheme>()?;
+    Ok(())
+}
+
+/// MarkdownTheme(proto=None, **kwargs)
+/// --
+///
+/// The appearance of a :func:`~pyiced.markdown()`.
+///
+/// Parameters
+/// ----------
+/// proto : Optional[MarkdownTheme]
+///     Source theme to clone and modify.
+/// text_size : int
+///     The size of normal text.
+/// text_color : Optional[Color]
+///     The color of normal text.
+/// font : Optional[Font]
+///     The font of normal text.
+/// emphasis_font : Optional[Font]
+///     The font of *emphasized* text.
+/// strong_font : Optional[Font]
+///     The font of **strong** text.
+/// heading_font : Optional[Font]
+///     The font of headings.
+/// heading_sizes : Tuple[int, int, int, int, int, int]
+///     The text sizes of the heading levels 1 to 6.
+/// heading_color : Optional[Color]
+///     The color of headings.
+/// code_font : Optional[Font]
+///     The font of inline code and code blocks, e.g. a monospaced font.
+/// code_size : int
+///     The text size of code blocks.
+/// code_color : Optional[Color]
+///     The color of inline code and code blocks.
+/// code_background : Optional[Color]
+///     The background of code blocks.
+/// link_color : Optional[Color]
+///     The color of links.
+/// quote_color : Optional[Color]
+///     The color of text in block quotes.
+/// spacing : int
+///     The vertical spacing between blocks, and the indentation of lists and block quotes.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     theme = MarkdownTheme(
+///         code_font=findfont('DejaVu Sans Mono').load(),
+///         heading_font=findfont('DejaVu Serif', weight='bold').load(),
+///     )
+#[pyclass(name = "MarkdownTheme", module = "pyiced")]
+#[derive(Debug, Clone, Default, Copy)]
+pub(crate) struct WrappedMarkdownTheme(pub MarkdownTheme);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MarkdownTheme(pub Style);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Style {
+    pub text_size: u16,
+    pub text_color: Option<Color>,
+    pub font: Option<Font>,
+    pub emphasis_font: Option<Font>,
+    pub strong_font: Option<Font>,
+    pub heading_font: Option<Font>,
+    pub heading_sizes: (u16, u16, u16, u16, u16, u16),
+    pub heading_color: Option<Color>,
+    pub code_font: Option<Font>,
+    pub code_size: u16,
+    pub code_color: Option<Color>,
+    pub code_background: Option<Color>,
+    pub link_color: Option<Color>,
+    pub quote_color: Option<Color>,
+    pub spacing: u16,
+}
+
+impl Default for MarkdownTheme {
+    fn default() -> Self {
+        Self(Style {
+            text_size: 20,
+            text_color: None,
+            font: None,
+            emphasis_font: None,
+            strong_font: None,
+            heading_font: None,
+            heading_sizes: (36, 30, 26, 22, 20, 18),
+            heading_color: None,
+            code_font: None,
+            code_size: 18,
+            code_color: None,
+            code_background: Some(Color::from_rgb(0.93, 0.93, 0.93)),
+            link_color: Some(Color::from_rgb(0.0, 0.35, 0.8)),
+            quote_color: Some(Color::from_rgb(0.4, 0.4, 0.4)),
+            spacing: 10,
+        })
+    }
+}
+
+impl MarkdownTheme {
+    pub(crate) fn heading_size(&self, level: usize) -> u16 {
+        let (h1, h2, h3, h4, h5, h6) = self.0.heading_sizes;
+        match level {
+            1 => h1,
+            2 => h2,
+            3 => h3,
+            4 => h4,
+            5 => h5,
+            _ => h6,
+        }
+    }
+}
+
+#[pymethods]
+impl WrappedMarkdownTheme {
+    #[args(proto = "None", kwargs = "**")]
+    #[new]
+    fn new(proto: Option<&Self>, kwargs: Option<&PyDict>) -> PyResult<Self> {
+        let proto = proto.map_or_else(MarkdownTheme::default, |p| p.0);
+        extract_multiple!(
+            kwargs,
+            proto,
+            text_size,
+            text_color,
+            font,
+            emphasis_font,
+            strong_font,
+            heading_font,
+            heading_sizes,
+            heading_color,
+            code_font,
+            code_size,
+            code_color,
+            code_background,
+            link_color,
+            quote_color,
+            spacing,
+        )
+    }
+}
+
+getters! {
+    WrappedMarkdownTheme => |&WrappedMarkdownTheme(MarkdownTheme(ref o))| o,
+    text_size -> "int" u16,
+    text_color -> "Optional[Color]" Option<WrappedColor>,
+    font -> "Optional[Font]" Option<WrappedFont>,
+    emphasis_font -> "Optional[Font]" Option<WrappedFont>,
+    strong_font -> "Optional[Font]" Option<WrappedFont>,
+    heading_font -> "Optional[Font]" Option<WrappedFont>,
+    heading_sizes -> "Tuple[int, int, int, int, int, int]" (u16, u16, u16, u16, u16, u16),
+    heading_color -> "Optional[Color]" Option<WrappedColor>,
+    code_font -> "Optional[Font]" Option<WrappedFont>,
+    code_size -> "int" u16,
+    code_color -> "Optional[Color]" Option<WrappedColor>,
+    code_background -> "Optional[Color]" Option<WrappedColor>,
+    link_color -> "Optional[Color]" Option<WrappedColor>,
+    quote_color -> "Optional[Color]" Option<WrappedColor>,
+    spacing -> "int" u16,
+}
@@ -20,6 +20,8 @@ init_mod! {
         CheckboxStyle, CheckboxStyleSheet, WrappedCheckboxStyle, WrappedCheckboxStyleSheet,
     },
     container -> { ContainerStyle, WrappedContainerStyle },
+    list_box -> { ListBoxStyle, WrappedListBoxStyle },
+    markdown -> { MarkdownTheme, WrappedMarkdownTheme },
     pane_grid -> { PaneGridStyle, WrappedPaneGridStyle },
     pick_list -> {
         PickListMenu, PickListStyle, PickListStyleSheet, WrappedPickListMenu, WrappedPickListStyle,
@@ -38,4 +40,7 @@ init_mod! {
     text_input -> {
         TextInputStyle, TextInputStyleSheet, WrappedTextInputStyle, WrappedTextInputStyleSheet,
     },
+    toggler -> {
+        TogglerStyle, TogglerStyleSheet, WrappedTogglerStyle, WrappedTogglerStyleSheet,
+    },
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::needless_option_as_deref)]
 
 use iced::text_input::{Style, StyleSheet};
-use iced::Color;
+use iced::{Background, Color};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
@@ -157,6 +157,27 @@ impl WrappedTextInputStyleSheet {
     }
 }
 
+impl TextInputStyleSheet {
+    /// Without background and border, so that the input looks like a plain text.
+    pub(crate) fn plain() -> Self {
+        let dflt = Box::<dyn StyleSheet>::default();
+        let style = Style {
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+        Self {
+            active: style,
+            focused: style,
+            hovered: style,
+            placeholder_color: dflt.placeholder_color(),
+            value_color: dflt.value_color(),
+            selection_color: dflt.selection_color(),
+        }
+    }
+}
+
 impl StyleSheet for TextInputStyleSheet {
     fn active(&self) -> Style {
         self.active
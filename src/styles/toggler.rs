@@ -0,0 +1,254 @@
+#![allow(clippy::needless_option_as_deref)]
+
+use iced::Color;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::common::debug_err;
+use crate::extractor::{Unextract, Unextractor};
+use crate::wrapped::WrappedColor;
+use crate::{extract_multiple, format_to_string_ignore, getters};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedTogglerStyle>()?;
+    m.add_class::<WrappedTogglerStyleSheet>()?;
+    Ok(())
+}
+
+/// The appearance of a toggler.
+///
+/// iced 0.3 has no toggler, so this mirrors `iced_style::toggler::Style` of later versions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Style {
+    pub background: Color,
+    pub background_border: Option<Color>,
+    pub foreground: Color,
+    pub foreground_border: Option<Color>,
+}
+
+pub(crate) trait StyleSheet {
+    fn active(&self, is_active: bool) -> Style;
+
+    fn hovered(&self, is_active: bool) -> Style;
+}
+
+struct DefaultStyle;
+
+impl StyleSheet for DefaultStyle {
+    fn active(&self, is_active: bool) -> Style {
+        Style {
+            background: match is_active {
+                true => Color::from_rgb(0.0, 1.0, 0.0),
+                false => Color::from_rgb(0.7, 0.7, 0.7),
+            },
+            background_border: None,
+            foreground: Color::WHITE,
+            foreground_border: None,
+        }
+    }
+
+    fn hovered(&self, is_active: bool) -> Style {
+        Style {
+            foreground: Color::from_rgb(0.95, 0.95, 0.95),
+            ..self.active(is_active)
+        }
+    }
+}
+
+impl Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(DefaultStyle)
+    }
+}
+
+/// TogglerStyle(proto=None, **kwargs)
+/// --
+///
+/// The appearance of a :func:`~pyiced.toggler()` for some state.
+///
+/// Parameters
+/// ----------
+/// proto : Optional[Union[TogglerStyle, str]]
+///     Source style sheet to clone and modify.
+///     Defaults to a green track if the toggler is switched on, and a gray track otherwise.
+///
+///     The valid string values are "active", "hovered", "active_on" and "hovered_on",
+///     same as the argument for :class:`pyiced.~TogglerStyleSheet`.
+///
+///     None is the same as "active".
+/// background : Color
+///     The color of the track.
+/// background_border : Optional[Color]
+///     The border color of the track. Defaults to the background color.
+/// foreground : Color
+///     The color of the knob.
+/// foreground_border : Optional[Color]
+///     The border color of the knob. Defaults to the foreground color.
+///
+/// See also
+/// --------
+/// :class:`~pyiced.TogglerStyleSheet`
+#[pyclass(name = "TogglerStyle", module = "pyiced")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WrappedTogglerStyle(pub TogglerStyle);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TogglerStyle(pub Style);
+
+#[pymethods]
+impl WrappedTogglerStyle {
+    #[args(proto = "None", kwargs = "**")]
+    #[new]
+    fn new(proto: Option<&PyAny>, kwargs: Option<&PyDict>) -> PyResult<Self> {
+        let proto = match proto {
+            Some(proto) => match proto.extract() {
+                Ok(Self(proto)) => proto.0,
+                Err(_) => match proto.downcast::<PyString>() {
+                    Ok(s) => match s.to_str()? {
+                        "active" => Box::<dyn StyleSheet>::default().active(false),
+                        "hovered" => Box::<dyn StyleSheet>::default().hovered(false),
+                        "active_on" => Box::<dyn StyleSheet>::default().active(true),
+                        "hovered_on" => Box::<dyn StyleSheet>::default().hovered(true),
+                        s => {
+                            return Err(PyErr::new::<PyValueError, _>(format_to_string_ignore!(
+                                "Unknown proto value: {:#}",
+                                s
+                            )));
+                        },
+                    },
+                    Err(err) => {
+                        return Err(debug_err::<PyTypeError, _>(err));
+                    },
+                },
+            },
+            None => Box::<dyn StyleSheet>::default().active(false),
+        };
+
+        extract_multiple!(
+            kwargs,
+            TogglerStyle(proto),
+            background,
+            background_border,
+            foreground,
+            foreground_border,
+        )
+    }
+}
+
+impl Unextract<WrappedTogglerStyle> for Unextractor<'_, Style> {
+    fn unextract(self) -> WrappedTogglerStyle {
+        WrappedTogglerStyle(TogglerStyle(*self.0))
+    }
+}
+
+getters! {
+    WrappedTogglerStyle => |&WrappedTogglerStyle(TogglerStyle(ref o))| o,
+    background -> "Color" WrappedColor,
+    background_border -> "Optional[Color]" Option<WrappedColor>,
+    foreground -> "Color" WrappedColor,
+    foreground_border -> "Optional[Color]" Option<WrappedColor>,
+}
+
+/// TogglerStyleSheet(active, hovered=None, active_on=None, hovered_on=None)
+/// --
+///
+/// The appearance of a :func:`~pyiced.toggler()`.
+///
+/// Parameters
+/// ----------
+/// active : TogglerStyle
+///     Normal style of this toggler.
+/// hovered : Optional[TogglerStyle]
+///     Style when hovering over the toggler. Defaults to the same style as "active".
+/// active_on : Optional[TogglerStyle]
+///     Style of this toggler when it is switched on. Defaults to the same style as "active".
+/// hovered_on : Optional[TogglerStyle]
+///     Style when hovering over the switched on toggler.
+///     If None or absent, it defaults to the first argument with an explicit value in
+///     "hovered", "active_on" or "active".
+///
+/// See also
+/// --------
+/// :func:`~pyiced.toggler()`
+#[pyclass(name = "TogglerStyleSheet", module = "pyiced")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WrappedTogglerStyleSheet(pub TogglerStyleSheet);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TogglerStyleSheet {
+    pub active: Style,
+    pub hovered: Style,
+    pub active_on: Style,
+    pub hovered_on: Style,
+}
+
+getters! {
+    WrappedTogglerStyleSheet => |&WrappedTogglerStyleSheet(ref o)| o,
+    active -> "TogglerStyle" WrappedTogglerStyle,
+    hovered -> "TogglerStyle" WrappedTogglerStyle,
+    active_on -> "TogglerStyle" WrappedTogglerStyle,
+    hovered_on -> "TogglerStyle" WrappedTogglerStyle,
+}
+
+#[pymethods]
+impl WrappedTogglerStyleSheet {
+    #[new]
+    fn new(
+        active: &WrappedTogglerStyle,
+        hovered: Option<&WrappedTogglerStyle>,
+        active_on: Option<&WrappedTogglerStyle>,
+        hovered_on: Option<&WrappedTogglerStyle>,
+    ) -> Self {
+        let active = active.0.0;
+        let hovered_on = [hovered_on, hovered, active_on]
+            .iter()
+            .find_map(|o| o.map(|o| o.0.0))
+            .unwrap_or(active);
+        let hovered = hovered.map_or(active, |s| s.0.0);
+        let active_on = active_on.map_or(active, |s| s.0.0);
+        Self(TogglerStyleSheet {
+            active,
+            hovered,
+            active_on,
+            hovered_on,
+        })
+    }
+}
+
+impl Default for TogglerStyleSheet {
+    fn default() -> Self {
+        let default = Box::<dyn StyleSheet>::default();
+        Self {
+            active: default.active(false),
+            hovered: default.hovered(false),
+            active_on: default.active(true),
+            hovered_on: default.hovered(true),
+        }
+    }
+}
+
+impl TogglerStyleSheet {
+    pub(crate) fn style(&self, is_active: bool, is_hovered: bool) -> Style {
+        match is_hovered {
+            true => self.hovered(is_active),
+            false => self.active(is_active),
+        }
+    }
+}
+
+impl StyleSheet for TogglerStyleSheet {
+    fn active(&self, is_active: bool) -> Style {
+        match is_active {
+            true => self.active_on,
+            false => self.active,
+        }
+    }
+
+    fn hovered(&self, is_active: bool) -> Style {
+        match is_active {
+            true => self.hovered_on,
+            false => self.hovered,
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use std::any::TypeId;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures_util::future::{select, Either};
+use futures_util::pin_mut;
+use futures_util::stream::{unfold, BoxStream};
+use iced::{Element, Image, Length, Point, Rectangle, Subscription};
+use iced_native::layout::{Limits, Node};
+use iced_native::subscription::Recipe;
+use iced_native::{mouse, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use parking_lot::{const_mutex, Mutex};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+use tokio::sync::Notify;
+
+use crate::assign;
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{AnimatedImage, WrappedAnimatedImageHandle, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_animated_image, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AnimatedImageBuilder {
+    pub handle: Arc<AnimatedImage>,
+    pub token: Option<Py<PyAny>>,
+    pub playing: bool,
+    pub looping: bool,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+}
+
+impl GCProtocol for AnimatedImageBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        if let Some(token) = &self.token {
+            visit.call(token)?;
+        }
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "animated_image")]
+/// animated_image($module, /, handle, *, token=None, playing=True, loop=True, width=None, height=None)
+/// --
+///
+/// A frame that displays an animated image while keeping aspect ratio.
+///
+/// The frames are advanced automatically, there is no need to add a subscription.
+///
+/// Parameters
+/// ----------
+/// handle : AnimatedImageHandle
+///     The handle of the image.
+/// token : Optional[object]
+///     When a non-looping animation reached its last frame,
+///     a message ``(token, "finished")`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// playing : bool
+///     If False, the animation is paused at its current frame.
+/// loop : bool
+///     If False, the animation stops at its last frame.
+/// width : Optional[Length]
+///     The width of the image.
+/// heigth : Optional[Length]
+///     The height of the image.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created image element.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     SPINNER = AnimatedImageHandle.from_path(Path(__file__).parent / 'spinner.gif')
+///
+///     def view(self):
+///         return animated_image(SPINNER, playing=self.__loading)
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.image()`
+/// * :meth:`~pyiced.AnimatedImageHandle.restart()`
+fn make_animated_image(
+    handle: &WrappedAnimatedImageHandle,
+    token: Option<Py<PyAny>>,
+    playing: Option<bool>,
+    r#loop: Option<bool>,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+) -> WrappedWidgetBuilder {
+    let el = AnimatedImageBuilder {
+        handle: handle.0.clone(),
+        token,
+        playing: playing.unwrap_or(true),
+        looping: r#loop.unwrap_or(true),
+        width: width.map(|o| o.0),
+        height: height.map(|o| o.0),
+    };
+    el.into()
+}
+
+impl ToNative for AnimatedImageBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        Element::new(Animation {
+            handle: self.handle.clone(),
+            token: self.token.clone(),
+            playing: self.playing,
+            looping: self.looping,
+            width: self.width,
+            height: self.height,
+        })
+    }
+}
+
+/// Selects the frame when it is drawn, so that building the view has no side effects.
+struct Animation {
+    handle: Arc<AnimatedImage>,
+    token: Option<Py<PyAny>>,
+    playing: bool,
+    looping: bool,
+    width: Option<Length>,
+    height: Option<Length>,
+}
+
+impl Animation {
+    fn image(&self, index: usize) -> Image {
+        let el = Image::new(self.handle.frames[index].0.clone());
+        assign!(el, self, width, height)
+    }
+
+    /// Updates the playback position, schedules the next frame, and returns the current frame.
+    fn advance(&self) -> usize {
+        let now = Instant::now();
+        let mut playback = self.handle.playback.lock();
+        match (self.playing, playback.resumed) {
+            (true, None) => playback.resumed = Some(now),
+            (false, Some(resumed)) => {
+                playback.offset += now.saturating_duration_since(resumed);
+                playback.resumed = None;
+            },
+            _ => {},
+        }
+
+        let elapsed = playback.elapsed(now);
+        let (index, next_frame) = self.handle.locate(elapsed, self.looping);
+        if self.playing {
+            match next_frame {
+                Some(next_frame) => Ticker::schedule(now + next_frame),
+                None if !self.looping && !playback.finished && !self.handle.duration.is_zero() => {
+                    playback.finished = true;
+                    if let Some(token) = &self.token {
+                        Ticker::finished(token.clone());
+                    }
+                },
+                None => {},
+            }
+        }
+        index
+    }
+}
+
+impl Widget<Message, Renderer> for Animation {
+    fn width(&self) -> Length {
+        self.width.unwrap_or(Length::Shrink)
+    }
+
+    fn height(&self) -> Length {
+        self.height.unwrap_or(Length::Shrink)
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        // all frames have the same size
+        Widget::<Message, Renderer>::layout(&self.image(0), renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let image = self.image(self.advance());
+        Widget::<Message, Renderer>::draw(
+            &image,
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+        Widget::<Message, Renderer>::hash_layout(&self.image(0), state);
+    }
+}
+
+/// Wakes the app when the next frame of any playing animation is due.
+///
+/// The ticker is always subscribed to, but it sleeps until an animation is shown.
+struct Ticker {
+    deadline: Option<Instant>,
+    finished: VecDeque<Py<PyAny>>,
+}
+
+static TICKER: Mutex<Ticker> = const_mutex(Ticker {
+    deadline: None,
+    finished: VecDeque::new(),
+});
+
+static TICKER_CHANGED: Notify = Notify::const_new();
+
+impl Ticker {
+    fn schedule(deadline: Instant) {
+        let mut ticker = TICKER.lock();
+        ticker.deadline = Some(match ticker.deadline {
+            Some(old) => old.min(deadline),
+            None => deadline,
+        });
+        TICKER_CHANGED.notify_one();
+    }
+
+    fn finished(token: Py<PyAny>) {
+        TICKER.lock().finished.push_back(token);
+        TICKER_CHANGED.notify_one();
+    }
+}
+
+pub(crate) fn subscription() -> Subscription<Message> {
+    Subscription::from_recipe(TickerRecipe)
+}
+
+struct TickerRecipe;
+
+impl<H, E> Recipe<H, E> for TickerRecipe
+where
+    H: Hasher,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut H) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<Self::Output> {
+        Box::pin(unfold((), |()| async {
+            loop {
+                let (token, deadline) = {
+                    let mut ticker = TICKER.lock();
+                    (ticker.finished.pop_front(), ticker.deadline)
+                };
+                if let Some(token) = token {
+                    let message = Python::with_gil(|py| (token, "finished").into_py(py));
+                    return Some((Message::Python(message), ()));
+                }
+                match deadline {
+                    Some(deadline) => {
+                        let sleep = tokio::time::sleep_until(deadline.into());
+                        let changed = TICKER_CHANGED.notified();
+                        pin_mut!(sleep, changed);
+                        if let Either::Right(_) = select(sleep, changed).await {
+                            // an earlier deadline or a finished animation
+                            continue;
+                        }
+                        TICKER.lock().deadline = None;
+                        // redrawing the animations will schedule their next frames
+                        return Some((Message::None, ()));
+                    },
+                    None => TICKER_CHANGED.notified().await,
+                }
+            }
+        }))
+    }
+}
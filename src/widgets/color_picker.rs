@@ -0,0 +1,889 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use iced::text_input::StyleSheet;
+use iced::{Color, Element, Font, Length, Point, Rectangle, Size, TextInput, Vector};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{event, keyboard, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::triangle::{Mesh2D, Vertex2D};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{ColorArea, ColorPickerState, ColorState, WrappedColorPickerState};
+use crate::styles::{TextInputStyleSheet, WrappedTextInputStyleSheet};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{color_to_rgba8, WrappedColor, WrappedFont};
+use crate::{format_to_string_ignore, make_with_state};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_color_picker, m)?)?;
+    Ok(())
+}
+
+const SPACING: f32 = 10.0;
+const STRIP_WIDTH: f32 = 20.0;
+const SWATCH_SIZE: f32 = 16.0;
+const SWATCH_SPACING: f32 = 4.0;
+const CHECKER_SIZE: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum TextFormat {
+    Hex,
+    Rgb,
+    Hsv,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ColorPickerBuilder {
+    pub state: ColorPickerState,
+    pub token: Py<PyAny>,
+    pub color: Color,
+    pub swatches: Vec<Color>,
+    pub format: TextFormat,
+    pub alpha: bool,
+    pub size: u16,
+    pub padding: u16,
+    pub text_size: Option<u16>,
+    pub font: Option<Font>,
+    pub style: Option<TextInputStyleSheet>,
+}
+
+impl GCProtocol for ColorPickerBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "color_picker")]
+/// color_picker($module, /, token, state, color, *, swatches=None, format="hex", alpha=True, size=None, padding=None, text_size=None, font=None, style=None)
+/// --
+///
+/// A widget to choose a color.
+///
+/// The saturation and value are chosen in a square, the hue and the opacity in two strips next to it.
+/// Below the square, a text field shows the color, and the user can type in a new color in any of the notations
+/// ``#rrggbb``, ``#rrggbbaa``, ``#rgb``, ``#rgba``, ``rgb(255, 128, 0)``, ``rgba(255, 128, 0, 0.5)``,
+/// ``hsv(30, 100%, 100%)`` or ``hsva(30, 100%, 100%, 0.5)``.
+/// The typed color is applied when it is valid, and the text is reformatted when the user presses enter
+/// or clicks somewhere else.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user chooses a color, a message ``(token, new_color)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// state : ColorPickerState
+///     Current state of the color picker. The same object must be given between calls.
+/// color : Color
+///     The current color.
+/// swatches : Optional[Iterable[Color]]
+///     A palette of colors that are shown below the text field, and can be chosen with a click,
+///     e.g. some colors of :mod:`pyiced.css_color`.
+/// format : Literal['hex', 'rgb', 'hsv']
+///     The notation that is used to show the color in the text field.
+/// alpha : bool
+///     Whether to show the strip to choose the opacity. If False, the opacity of ``color`` is kept.
+/// size : Optional[int]
+///     The width and height of the saturation/value square. Defaults to 200.
+/// padding : Optional[int]
+///     The padding of the text field. Defaults to 5.
+/// text_size : Optional[int]
+///     The text size of the text field.
+/// font : Optional[Font]
+///     The font of the text field.
+/// style : Optional[TextInputStyleSheet]
+///     The style of the text field. Its "border_color" is used for the swatches, too.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created color picker.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     from pyiced import css_color
+///
+///     def view(self):
+///         return color_picker(
+///             'fill', self.__fill_state, self.__fill,
+///             swatches=[css_color.RED, css_color.ORANGE, css_color.GOLD, css_color.GREEN],
+///         )
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('fill', value):
+///                 self.__fill = value
+///
+/// See also
+/// --------
+/// * :class:`~pyiced.Color`
+/// * :mod:`pyiced.css_color`
+fn make_color_picker(
+    token: Py<PyAny>,
+    state: &WrappedColorPickerState,
+    color: &WrappedColor,
+    swatches: Option<&PyAny>,
+    format: Option<&str>,
+    alpha: Option<bool>,
+    size: Option<u16>,
+    padding: Option<u16>,
+    text_size: Option<u16>,
+    font: Option<&WrappedFont>,
+    style: Option<&WrappedTextInputStyleSheet>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let swatches = match swatches {
+        Some(swatches) => swatches
+            .iter()?
+            .map(|swatch| Ok(swatch?.extract::<WrappedColor>()?.0))
+            .collect::<PyResult<_>>()?,
+        None => Vec::new(),
+    };
+    let format = match format.unwrap_or("hex") {
+        "hex" => TextFormat::Hex,
+        "rgb" => TextFormat::Rgb,
+        "hsv" => TextFormat::Hsv,
+        s => {
+            return Err(PyErr::new::<PyValueError, _>(format_to_string_ignore!(
+                "Unknown format value: {:#}",
+                s,
+            )));
+        },
+    };
+    let el = ColorPickerBuilder {
+        state: state.0.clone(),
+        token,
+        color: color.0,
+        swatches,
+        format,
+        alpha: alpha.unwrap_or(true),
+        size: size.unwrap_or(200),
+        padding: padding.unwrap_or(5),
+        text_size,
+        font: font.map(|o| o.0),
+        style: style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ColorPickerBuilder {
+    /// The hue, saturation and value of the current color.
+    fn hsv(&self, last: Option<(Color, [f32; 3])>) -> [f32; 3] {
+        match last {
+            Some((color, hsv)) if color == self.color => hsv,
+            _ => rgb_to_hsv(self.color),
+        }
+    }
+
+    fn format(&self, hsv: [f32; 3]) -> String {
+        let [r, g, b, a] = color_to_rgba8(&self.color);
+        let alpha = match self.alpha && a != 255 {
+            true => Some((self.color.a * 100.0).round() / 100.0),
+            false => None,
+        };
+        let [h, s, v] = hsv;
+        let (h, s, v) = (h.round() % 360.0, (s * 100.0).round(), (v * 100.0).round());
+        let text = match (self.format, alpha) {
+            (TextFormat::Hex, None) => format_to_string_ignore!("#{:02x}{:02x}{:02x}", r, g, b),
+            (TextFormat::Hex, Some(_)) => {
+                format_to_string_ignore!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+            },
+            (TextFormat::Rgb, None) => format_to_string_ignore!("rgb({}, {}, {})", r, g, b),
+            (TextFormat::Rgb, Some(a)) => {
+                format_to_string_ignore!("rgba({}, {}, {}, {})", r, g, b, a)
+            },
+            (TextFormat::Hsv, None) => format_to_string_ignore!("hsv({}, {}%, {}%)", h, s, v),
+            (TextFormat::Hsv, Some(a)) => {
+                format_to_string_ignore!("hsva({}, {}%, {}%, {})", h, s, v, a)
+            },
+        };
+        text.into_owned()
+    }
+}
+
+impl ToNative for ColorPickerBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        color_picker_with_state(&self.state, |state| {
+            let ColorState {
+                input,
+                editing,
+                focused,
+                dragging,
+                hsv,
+            } = state;
+            let text = match editing {
+                Some(text) => text.clone(),
+                None => self.format(self.hsv(*hsv)),
+            };
+
+            // The text is checked before it is sent to the app, so keep it for on_event().
+            let changed = Rc::new(RefCell::new(None));
+            let on_change = {
+                let changed = Rc::clone(&changed);
+                move |s| {
+                    *changed.borrow_mut() = Some(s);
+                    Message::None
+                }
+            };
+            let el = TextInput::new(input, "", &text, on_change)
+                .width(Length::Fill)
+                .padding(self.padding);
+            let el = match self.font {
+                Some(font) => el.font(font),
+                None => el,
+            };
+            let el = match self.text_size {
+                Some(size) => el.size(size),
+                None => el,
+            };
+            let el = match self.style {
+                Some(style) => el.style(style),
+                None => el,
+            };
+
+            Ok(ColorPicker {
+                input: el,
+                editing,
+                focused,
+                dragging,
+                hsv,
+                changed,
+                style: match self.style {
+                    Some(style) => Box::new(style),
+                    None => Default::default(),
+                },
+                builder: self.clone(),
+            })
+        })
+    }
+}
+
+make_with_state! {
+    color_picker_with_state(
+        crate::widgets::color_picker::ColorPicker,
+        crate::widgets::color_picker::ColorPicker<'this>,
+        crate::states::ColorState,
+    );
+}
+
+pub(crate) struct ColorPicker<'a> {
+    input: TextInput<'a, Message>,
+    editing: &'a mut Option<String>,
+    focused: &'a mut bool,
+    dragging: &'a mut Option<ColorArea>,
+    hsv: &'a mut Option<(Color, [f32; 3])>,
+    changed: Rc<RefCell<Option<String>>>,
+    style: Box<dyn StyleSheet>,
+    builder: ColorPickerBuilder,
+}
+
+/// The positions of the parts of a color picker.
+struct Geometry {
+    square: Rectangle,
+    hue: Rectangle,
+    alpha: Option<Rectangle>,
+    preview: Rectangle,
+    swatches: Vec<Rectangle>,
+}
+
+impl ColorPicker<'_> {
+    fn text_size(&self, renderer: &Renderer) -> u16 {
+        self.builder
+            .text_size
+            .unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// The width of the square and the strips.
+    fn picker_width(&self) -> f32 {
+        let strips = match self.builder.alpha {
+            true => 2.0,
+            false => 1.0,
+        };
+        self.builder.size as f32 + strips * (SPACING + STRIP_WIDTH)
+    }
+
+    fn swatch_columns(&self) -> usize {
+        let columns = (self.picker_width() + SWATCH_SPACING) / (SWATCH_SIZE + SWATCH_SPACING);
+        (columns as usize).max(1)
+    }
+
+    fn geometry(&self, renderer: &Renderer, bounds: Rectangle) -> Geometry {
+        let side = self.builder.size as f32;
+        let square = Rectangle {
+            x: bounds.x,
+            y: bounds.y,
+            width: side,
+            height: side,
+        };
+        let hue = Rectangle {
+            x: square.x + side + SPACING,
+            width: STRIP_WIDTH,
+            ..square
+        };
+        let alpha = match self.builder.alpha {
+            true => Some(Rectangle {
+                x: hue.x + STRIP_WIDTH + SPACING,
+                ..hue
+            }),
+            false => None,
+        };
+
+        let input_height = (self.text_size(renderer) + 2 * self.builder.padding) as f32;
+        let preview = Rectangle {
+            x: bounds.x,
+            y: bounds.y + side + SPACING,
+            width: input_height,
+            height: input_height,
+        };
+
+        let columns = self.swatch_columns();
+        let top = preview.y + input_height + SPACING;
+        let swatches = (0..self.builder.swatches.len())
+            .map(|index| Rectangle {
+                x: bounds.x + (index % columns) as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+                y: top + (index / columns) as f32 * (SWATCH_SIZE + SWATCH_SPACING),
+                width: SWATCH_SIZE,
+                height: SWATCH_SIZE,
+            })
+            .collect();
+
+        Geometry {
+            square,
+            hue,
+            alpha,
+            preview,
+            swatches,
+        }
+    }
+
+    fn emit(&mut self, color: Color, hsv: [f32; 3], messages: &mut Vec<Message>) {
+        let color = match self.builder.alpha {
+            true => color,
+            false => Color {
+                a: self.builder.color.a,
+                ..color
+            },
+        };
+        let old = self.hsv.replace((color, hsv));
+        if color == self.builder.color {
+            // E.g. the hue of a gray was changed: the color stays the same, but the text changes.
+            if old != Some((color, hsv)) {
+                messages.push(Message::None);
+            }
+            return;
+        }
+        let message = Python::with_gil(|py| (&self.builder.token, WrappedColor(color)).into_py(py));
+        messages.push(Message::Python(message));
+    }
+
+    /// The user dragged a handle to the point.
+    fn drag(
+        &mut self,
+        area: ColorArea,
+        geometry: &Geometry,
+        point: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let bounds = match area {
+            ColorArea::Square => geometry.square,
+            ColorArea::Hue => geometry.hue,
+            ColorArea::Alpha => match geometry.alpha {
+                Some(bounds) => bounds,
+                None => return,
+            },
+        };
+        let x = ((point.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+        let y = ((point.y - bounds.y) / bounds.height).clamp(0.0, 1.0);
+
+        let [mut h, mut s, mut v] = self.builder.hsv(*self.hsv);
+        let mut a = self.builder.color.a;
+        match area {
+            ColorArea::Square => {
+                s = x;
+                v = 1.0 - y;
+            },
+            ColorArea::Hue => h = y * 360.0,
+            ColorArea::Alpha => a = 1.0 - y,
+        }
+        *self.editing = None;
+        self.emit(hsv_to_rgb([h, s, v], a), [h, s, v], messages);
+    }
+
+    /// The user typed something. Only a valid color is sent to the app.
+    fn edit(&mut self, text: String, messages: &mut Vec<Message>) {
+        if let Some((color, hsv)) = parse_color(&text) {
+            self.emit(color, hsv, messages);
+        }
+        *self.editing = Some(text);
+    }
+
+    /// The user finished typing. The text is reformatted, or discarded if it is not a color.
+    fn commit(&mut self, messages: &mut Vec<Message>) {
+        if let Some(text) = self.editing.take() {
+            if let Some((color, hsv)) = parse_color(&text) {
+                self.emit(color, hsv, messages);
+            }
+            // The text was replaced, even if the color did not change.
+            messages.push(Message::None);
+        }
+    }
+
+    /// An outlined circle that is visible on light and dark colors.
+    fn draw_handle(&self, center: Point) -> Primitive {
+        let ring = |radius: f32, width: f32, color: Color| Primitive::Quad {
+            bounds: Rectangle {
+                x: center.x - radius,
+                y: center.y - radius,
+                width: 2.0 * radius,
+                height: 2.0 * radius,
+            },
+            background: Color::TRANSPARENT.into(),
+            border_radius: radius,
+            border_width: width,
+            border_color: color,
+        };
+        Primitive::Group {
+            primitives: vec![ring(6.0, 1.0, Color::BLACK), ring(5.0, 2.0, Color::WHITE)],
+        }
+    }
+
+    /// A bar across a strip that is visible on light and dark colors.
+    fn draw_bar(&self, bounds: Rectangle, y: f32) -> Primitive {
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x - 2.0,
+                y: y - 3.0,
+                width: bounds.width + 4.0,
+                height: 6.0,
+            },
+            background: Color::TRANSPARENT.into(),
+            border_radius: 2.0,
+            border_width: 2.0,
+            border_color: Color::WHITE,
+        }
+    }
+}
+
+impl Widget<Message, Renderer> for ColorPicker<'_> {
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, _limits: &Limits) -> Node {
+        let side = self.builder.size as f32;
+        let width = self.picker_width();
+        let input_height = (self.text_size(renderer) + 2 * self.builder.padding) as f32;
+
+        let input_limits = Limits::new(
+            Size::ZERO,
+            Size::new((width - input_height - SPACING).max(0.0), input_height),
+        );
+        let mut input = Widget::<Message, Renderer>::layout(&self.input, renderer, &input_limits);
+        input.move_to(Point::new(input_height + SPACING, side + SPACING));
+
+        let swatch_rows = self.builder.swatches.len().div_ceil(self.swatch_columns());
+        let swatches_height = match swatch_rows {
+            0 => 0.0,
+            rows => SPACING + rows as f32 * (SWATCH_SIZE + SWATCH_SPACING) - SWATCH_SPACING,
+        };
+        let height = side + SPACING + input_height + swatches_height;
+        Node::with_children(Size::new(width, height), vec![input])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let input = match layout.children().next() {
+            Some(input) => input,
+            None => return (Primitive::None, mouse::Interaction::default()),
+        };
+        let geometry = self.geometry(renderer, layout.bounds());
+        let [h, s, v] = self.builder.hsv(*self.hsv);
+        let color = self.builder.color;
+        let border_color = self.style.active().border_color;
+
+        let mut primitives = vec![
+            gradient(geometry.square, 16, 16, |x, y| {
+                hsv_to_rgb([h, x, 1.0 - y], 1.0)
+            }),
+            gradient(geometry.hue, 1, 36, |_, y| {
+                hsv_to_rgb([y * 360.0, 1.0, 1.0], 1.0)
+            }),
+        ];
+        if let Some(alpha) = geometry.alpha {
+            primitives.push(checkerboard(alpha));
+            primitives.push(gradient(alpha, 1, 1, |_, y| Color {
+                a: 1.0 - y,
+                ..color
+            }));
+        }
+
+        primitives.push(checkerboard(geometry.preview));
+        primitives.push(Primitive::Quad {
+            bounds: geometry.preview,
+            background: color.into(),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color,
+        });
+        for (bounds, &swatch) in geometry.swatches.iter().zip(&self.builder.swatches) {
+            primitives.push(Primitive::Quad {
+                bounds: *bounds,
+                background: swatch.into(),
+                border_radius: 0.0,
+                border_width: if swatch == color { 2.0 } else { 1.0 },
+                border_color,
+            });
+        }
+
+        // Meshes are drawn above the quads of the same layer, so the handles need a layer of their own.
+        let mut handles = vec![
+            self.draw_handle(Point::new(
+                geometry.square.x + s * geometry.square.width,
+                geometry.square.y + (1.0 - v) * geometry.square.height,
+            )),
+            self.draw_bar(
+                geometry.hue,
+                geometry.hue.y + h / 360.0 * geometry.hue.height,
+            ),
+        ];
+        if let Some(alpha) = geometry.alpha {
+            handles.push(self.draw_bar(alpha, alpha.y + (1.0 - color.a) * alpha.height));
+        }
+        primitives.push(Primitive::Clip {
+            bounds: Rectangle {
+                x: layout.bounds().x - 6.0,
+                y: layout.bounds().y - 6.0,
+                width: layout.bounds().width + 12.0,
+                height: layout.bounds().height + 12.0,
+            },
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Group {
+                primitives: handles,
+            }),
+        });
+
+        let (input, interaction) = Widget::<Message, Renderer>::draw(
+            &self.input,
+            renderer,
+            defaults,
+            input,
+            cursor_position,
+            viewport,
+        );
+        primitives.push(input);
+
+        let is_in_strip = geometry.hue.contains(cursor_position)
+            || geometry
+                .alpha
+                .is_some_and(|alpha| alpha.contains(cursor_position));
+        let interaction = if self.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if geometry.square.contains(cursor_position) {
+            mouse::Interaction::Crosshair
+        } else if is_in_strip
+            || geometry
+                .swatches
+                .iter()
+                .any(|s| s.contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.builder.swatches.len().hash(state);
+        self.builder.alpha.hash(state);
+        self.builder.size.hash(state);
+        self.builder.padding.hash(state);
+        self.builder.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let input = match layout.children().next() {
+            Some(input) => input,
+            None => return event::Status::Ignored,
+        };
+        let geometry = self.geometry(renderer, layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                // the text input loses its focus if the click was outside of it
+                let is_clicked = input.bounds().contains(cursor_position);
+                if *self.focused && !is_clicked {
+                    self.commit(messages);
+                }
+                *self.focused = is_clicked;
+
+                let area = if geometry.square.contains(cursor_position) {
+                    Some(ColorArea::Square)
+                } else if geometry.hue.contains(cursor_position) {
+                    Some(ColorArea::Hue)
+                } else if geometry.alpha.is_some_and(|a| a.contains(cursor_position)) {
+                    Some(ColorArea::Alpha)
+                } else {
+                    None
+                };
+                if let Some(area) = area {
+                    *self.dragging = Some(area);
+                    self.drag(area, &geometry, cursor_position, messages);
+                    return event::Status::Captured;
+                }
+
+                let swatch = geometry
+                    .swatches
+                    .iter()
+                    .position(|s| s.contains(cursor_position))
+                    .map(|index| self.builder.swatches[index]);
+                if let Some(swatch) = swatch {
+                    *self.editing = None;
+                    self.emit(swatch, rgb_to_hsv(swatch), messages);
+                    return event::Status::Captured;
+                }
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(area) = *self.dragging {
+                    self.drag(area, &geometry, cursor_position, messages);
+                    return event::Status::Captured;
+                }
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.dragging.is_some() =>
+            {
+                *self.dragging = None;
+                return event::Status::Captured;
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: KeyCode::Enter | KeyCode::NumpadEnter,
+                ..
+            }) if *self.focused => {
+                self.commit(messages);
+                return event::Status::Captured;
+            },
+            _ => {},
+        }
+
+        // The text input sends Message::None, which is dropped by the app, but still makes iced
+        // rebuild the view, so that the edited text is shown even if it is not a valid color.
+        let status =
+            self.input
+                .on_event(event, input, cursor_position, renderer, clipboard, messages);
+        let changed = self.changed.borrow_mut().take();
+        if let Some(text) = changed {
+            self.edit(text, messages);
+        }
+        status
+    }
+}
+
+/// A mesh of `columns` × `rows` cells over `bounds`. The colors in between the corners of the
+/// cells are interpolated. `color(x, y)` gets the relative position of a corner, from 0 to 1.
+fn gradient(
+    bounds: Rectangle,
+    columns: u32,
+    rows: u32,
+    color: impl Fn(f32, f32) -> Color,
+) -> Primitive {
+    let mut vertices = Vec::with_capacity(((columns + 1) * (rows + 1)) as usize);
+    for row in 0..=rows {
+        for column in 0..=columns {
+            let x = column as f32 / columns as f32;
+            let y = row as f32 / rows as f32;
+            vertices.push(Vertex2D {
+                position: [x * bounds.width, y * bounds.height],
+                color: color(x, y).into_linear(),
+            });
+        }
+    }
+
+    let stride = columns + 1;
+    let mut indices = Vec::with_capacity((6 * columns * rows) as usize);
+    for row in 0..rows {
+        for column in 0..columns {
+            let top_left = row * stride + column;
+            indices.extend_from_slice(&[
+                top_left,
+                top_left + 1,
+                top_left + stride,
+                top_left + 1,
+                top_left + stride + 1,
+                top_left + stride,
+            ]);
+        }
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(bounds.x, bounds.y),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: Mesh2D { vertices, indices },
+            size: bounds.size(),
+        }),
+    }
+}
+
+/// The usual gray checkerboard pattern behind transparent colors.
+fn checkerboard(bounds: Rectangle) -> Primitive {
+    let mut primitives = vec![Primitive::Quad {
+        bounds,
+        background: Color::WHITE.into(),
+        border_radius: 0.0,
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+    }];
+    let columns = (bounds.width / CHECKER_SIZE).ceil() as usize;
+    let rows = (bounds.height / CHECKER_SIZE).ceil() as usize;
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let x = column as f32 * CHECKER_SIZE;
+            let y = row as f32 * CHECKER_SIZE;
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + x,
+                    y: bounds.y + y,
+                    width: CHECKER_SIZE.min(bounds.width - x),
+                    height: CHECKER_SIZE.min(bounds.height - y),
+                },
+                background: Color::from_rgb(0.8, 0.8, 0.8).into(),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+    }
+    Primitive::Group { primitives }
+}
+
+fn hsv_to_rgb([h, s, v]: [f32; 3], a: f32) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let c = v * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    Color::new(r + m, g + m, b + m, a)
+}
+
+fn rgb_to_hsv(color: Color) -> [f32; 3] {
+    let Color { r, g, b, .. } = color;
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let h = if delta <= 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let s = match max > 0.0 {
+        true => delta / max,
+        false => 0.0,
+    };
+    [h, s, max]
+}
+
+/// Parses a color in hex, `rgb()`, `rgba()`, `hsv()` or `hsva()` notation.
+fn parse_color(text: &str) -> Option<(Color, [f32; 3])> {
+    let text = text.trim().to_ascii_lowercase();
+    if let Some((name, args)) = text.strip_suffix(')').and_then(|t| t.split_once('(')) {
+        // A number from 0 to `max`, or a percentage.
+        let fraction = |arg: &str, max: f32| {
+            let value = match arg.trim().strip_suffix('%') {
+                Some(percent) => percent.trim().parse::<f32>().ok()? / 100.0,
+                None => arg.trim().parse::<f32>().ok()? / max,
+            };
+            (0.0..=1.0).contains(&value).then_some(value)
+        };
+
+        let args: Vec<&str> = args.split(',').collect();
+        let a = match args.len() {
+            3 => 1.0,
+            4 => fraction(args[3], 1.0)?,
+            _ => return None,
+        };
+        return match name.trim() {
+            "rgb" | "rgba" => {
+                let color = Color::new(
+                    fraction(args[0], 255.0)?,
+                    fraction(args[1], 255.0)?,
+                    fraction(args[2], 255.0)?,
+                    a,
+                );
+                Some((color, rgb_to_hsv(color)))
+            },
+            "hsv" | "hsva" => {
+                let h = args[0].trim().trim_end_matches('°').parse::<f32>().ok()?;
+                if !h.is_finite() {
+                    return None;
+                }
+                let hsv = [
+                    h.rem_euclid(360.0),
+                    fraction(args[1], 100.0)?,
+                    fraction(args[2], 100.0)?,
+                ];
+                Some((hsv_to_rgb(hsv, a), hsv))
+            },
+            _ => None,
+        };
+    }
+
+    let hex = text.strip_prefix('#').unwrap_or(&text);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channels: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .filter_map(|c| c.to_digit(16))
+            .map(|d| d as u8 * 17)
+            .collect(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .filter_map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect(),
+        _ => return None,
+    };
+    let a = channels.get(3).map_or(1.0, |&a| a as f32 / 255.0);
+    let color = Color::from_rgba8(channels[0], channels[1], channels[2], a);
+    Some((color, rgb_to_hsv(color)))
+}
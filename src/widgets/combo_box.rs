@@ -0,0 +1,525 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use iced::{Element, Font, Length, Point, Rectangle, TextInput};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::overlay::menu::{self, Menu};
+use iced_native::{event, keyboard, mouse, overlay, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{ComboBoxState, ComboState, WrappedComboBoxState};
+use crate::styles::{
+    PickListMenu, TextInputStyleSheet, WrappedPickListMenu, WrappedTextInputStyleSheet,
+};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedLength};
+use crate::{format_to_string_ignore, make_with_state};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_combo_box, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Matching {
+    Prefix,
+    Substring,
+    Fuzzy,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ComboBoxBuilder {
+    pub state: ComboBoxState,
+    pub token: Py<PyAny>,
+    pub options: Vec<String>,
+    pub selected: Option<String>,
+    pub placeholder: String,
+    pub matching: Matching,
+    pub font: Option<Font>,
+    pub width: Length,
+    pub padding: u16,
+    pub size: Option<u16>,
+    pub style: Option<TextInputStyleSheet>,
+    pub menu_style: Option<PickListMenu>,
+}
+
+impl GCProtocol for ComboBoxBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "combo_box")]
+/// combo_box($module, /, token, state, options, selected=None, placeholder="", *, matching="substring", font=None, width=None, padding=None, size=None, style=None, menu_style=None)
+/// --
+///
+/// A text input to search in a list of options, with a drop down menu of the matching options.
+///
+/// While the input is focused, the up and down keys move through the matches, enter selects the highlighted match,
+/// and escape closes the menu. The search is case insensitive.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user selects a value, a message ``(token, new_value)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// state : ComboBoxState
+///     Current state of the combo box. The same object must be given between calls.
+/// options : Iterable[Optional[str]]
+///     Values to select from.
+/// selected : Optional[str]
+///     The currently selected value. It is shown while the user is not typing.
+/// placeholder : str
+///     Placeholder text if nothing is selected.
+/// matching : str
+///     How the typed text is compared to the options:
+///
+///     * "prefix": The option starts with the text.
+///     * "substring": The option contains the text.
+///     * "fuzzy": The option contains all characters of the text in the same order.
+///       The closest matches are shown first.
+/// font : Optional[Font]
+///     The font of the text and of the menu.
+/// width : Optional[Length]
+///     The width of the input element and its menu. Defaults to Length.FILL.
+/// padding : Optional[int]
+///     The padding of the text and of the menu options.
+/// size : Optional[int]
+///     The text size of the input element and of the menu.
+/// style : Optional[TextInputStyleSheet]
+///     Style of the input element.
+/// menu_style : Optional[PickListMenu]
+///     Style of the drop down menu.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created combo box.
+///
+/// Raises
+/// ------
+/// ValueError
+///     Unknown ``matching`` value.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return combo_box('timezone', self.__timezone_state, self.__timezones, self.__timezone)
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('timezone', value):
+///                 self.__timezone = value
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.pick_list()`
+/// * :func:`~pyiced.text_input()`
+fn make_combo_box(
+    py: Python,
+    token: Py<PyAny>,
+    state: &WrappedComboBoxState,
+    options: &PyAny,
+    selected: Option<String>,
+    placeholder: Option<String>,
+    matching: Option<&str>,
+    font: Option<&WrappedFont>,
+    width: Option<&WrappedLength>,
+    padding: Option<u16>,
+    size: Option<u16>,
+    style: Option<&WrappedTextInputStyleSheet>,
+    menu_style: Option<&WrappedPickListMenu>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let matching = match matching.unwrap_or("substring") {
+        "prefix" => Matching::Prefix,
+        "substring" => Matching::Substring,
+        "fuzzy" => Matching::Fuzzy,
+        s => {
+            return Err(PyErr::new::<PyValueError, _>(format_to_string_ignore!(
+                "Unknown matching value: {:#}",
+                s
+            )));
+        },
+    };
+    let options = options
+        .iter()?
+        .filter_map(|child| match child {
+            Ok(child) if !child.is_none() => match child.str() {
+                Ok(s) => Some(s.to_string()),
+                Err(err) => {
+                    err.print(py);
+                    None
+                },
+            },
+            Ok(_) => None,
+            Err(err) => {
+                err.print(py);
+                None
+            },
+        })
+        .collect();
+
+    let el = ComboBoxBuilder {
+        state: state.0.clone(),
+        token,
+        options,
+        selected,
+        placeholder: placeholder.unwrap_or_default(),
+        matching,
+        font: font.map(|o| o.0),
+        width: width.map_or(Length::Fill, |o| o.0),
+        padding: padding.unwrap_or(0),
+        size,
+        style: style.map(|o| o.0),
+        menu_style: menu_style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ComboBoxBuilder {
+    /// The options that match the typed text, best matches first.
+    fn matches(&self, query: &str) -> Vec<String> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return self.options.clone();
+        }
+
+        let mut scored: Vec<_> = self
+            .options
+            .iter()
+            .filter_map(|option| {
+                let label = option.to_lowercase();
+                let score = match self.matching {
+                    Matching::Prefix => label.starts_with(&query).then_some(0),
+                    Matching::Substring => label.contains(&query).then_some(0),
+                    Matching::Fuzzy => fuzzy_score(&query, &label),
+                };
+                score.map(|score| (score, option))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, _)| score);
+        scored
+            .into_iter()
+            .map(|(_, option)| option.clone())
+            .collect()
+    }
+}
+
+/// The number of skipped characters if all characters of `query` appear in `label` in order.
+fn fuzzy_score(query: &str, label: &str) -> Option<usize> {
+    let mut label = label.chars();
+    let mut skipped = 0;
+    for c in query.chars() {
+        loop {
+            if label.next()? == c {
+                break;
+            }
+            skipped += 1;
+        }
+    }
+    Some(skipped)
+}
+
+impl ToNative for ComboBoxBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        combo_box_with_state(&self.state, |state| {
+            let ComboState {
+                input,
+                menu,
+                query,
+                focused,
+                is_open,
+                hovered_option,
+                last_selection,
+            } = state;
+            let (text, matches) = match query {
+                Some(query) => (query.clone(), self.matches(query)),
+                None => (
+                    self.selected.clone().unwrap_or_default(),
+                    self.options.clone(),
+                ),
+            };
+
+            // The query is not sent to the app, so keep it for on_event().
+            let changed = Rc::new(RefCell::new(None));
+            let on_change = {
+                let changed = Rc::clone(&changed);
+                move |s| {
+                    *changed.borrow_mut() = Some(s);
+                    Message::None
+                }
+            };
+            let el = TextInput::new(input, &self.placeholder, &text, on_change)
+                .width(self.width)
+                .padding(self.padding);
+            let el = match self.font {
+                Some(font) => el.font(font),
+                None => el,
+            };
+            let el = match self.size {
+                Some(size) => el.size(size),
+                None => el,
+            };
+            let el = match self.style {
+                Some(style) => el.style(style),
+                None => el,
+            };
+
+            Ok(ComboBox {
+                input: el,
+                menu,
+                query,
+                focused,
+                is_open,
+                hovered_option,
+                last_selection,
+                changed,
+                matches,
+                builder: self.clone(),
+            })
+        })
+    }
+}
+
+make_with_state! {
+    combo_box_with_state(
+        crate::widgets::combo_box::ComboBox,
+        crate::widgets::combo_box::ComboBox<'this>,
+        crate::states::ComboState,
+    );
+}
+
+pub(crate) struct ComboBox<'a> {
+    input: TextInput<'a, Message>,
+    menu: &'a mut menu::State,
+    query: &'a mut Option<String>,
+    focused: &'a mut bool,
+    is_open: &'a mut bool,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<String>,
+    changed: Rc<RefCell<Option<String>>>,
+    matches: Vec<String>,
+    builder: ComboBoxBuilder,
+}
+
+impl ComboBox<'_> {
+    fn open(&mut self) {
+        let selected = self.builder.selected.as_ref();
+        *self.is_open = true;
+        *self.hovered_option = self
+            .matches
+            .iter()
+            .position(|option| Some(option) == selected);
+    }
+
+    /// Closes the menu and forgets the typed text.
+    fn close(&mut self) {
+        *self.is_open = false;
+        *self.query = None;
+    }
+
+    fn select(&mut self, value: String, messages: &mut Vec<Message>) {
+        self.close();
+        let message = Python::with_gil(|py| (&self.builder.token, value).into_py(py));
+        messages.push(Message::Python(message));
+    }
+
+    fn move_hovered(&mut self, down: bool) {
+        let last = match self.matches.len() {
+            0 => return,
+            len => len - 1,
+        };
+        *self.hovered_option = Some(match (*self.hovered_option, down) {
+            (Some(index), true) => (index + 1).min(last),
+            (Some(index), false) => index.saturating_sub(1),
+            (None, true) => 0,
+            (None, false) => last,
+        });
+    }
+
+    fn delegate(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        // The text input sends Message::None, so the view gets rebuilt with the new matches.
+        let status = self.input.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        );
+        let changed = self.changed.borrow_mut().take();
+        if let Some(text) = changed {
+            *self.query = Some(text);
+            *self.is_open = true;
+            *self.hovered_option = Some(0);
+            *self.menu = menu::State::default();
+        }
+        status
+    }
+}
+
+impl Widget<Message, Renderer> for ComboBox<'_> {
+    fn width(&self) -> Length {
+        self.builder.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        Widget::<Message, Renderer>::layout(&self.input, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        Widget::<Message, Renderer>::draw(
+            &self.input,
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.builder.width.hash(state);
+        self.builder.padding.hash(state);
+        self.builder.size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(value) = self.last_selection.take() {
+                    // the user clicked on an option in the menu
+                    self.select(value, messages);
+                    *self.focused = false;
+                    let _ = self.delegate(
+                        event,
+                        layout,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        messages,
+                    );
+                    return event::Status::Captured;
+                } else if *self.is_open && (cursor_position.x < 0.0 || cursor_position.y < 0.0) {
+                    // the click was inside the menu, but not on an option
+                    return event::Status::Captured;
+                }
+
+                let is_clicked = layout.bounds().contains(cursor_position);
+                if is_clicked && !*self.is_open {
+                    self.open();
+                } else if !is_clicked && (*self.is_open || *self.focused) {
+                    self.close();
+                }
+                *self.focused = is_clicked;
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if *self.focused => {
+                match key_code {
+                    KeyCode::Up | KeyCode::Down if !*self.is_open => self.open(),
+                    KeyCode::Up => self.move_hovered(false),
+                    KeyCode::Down => self.move_hovered(true),
+                    KeyCode::Enter | KeyCode::NumpadEnter => {
+                        let hovered = self.hovered_option.and_then(|i| self.matches.get(i));
+                        match (*self.is_open, hovered.cloned()) {
+                            (true, Some(value)) => self.select(value, messages),
+                            _ => return event::Status::Ignored,
+                        }
+                    },
+                    KeyCode::Escape if *self.is_open || self.query.is_some() => self.close(),
+                    _ => {
+                        return self.delegate(
+                            event,
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            messages,
+                        );
+                    },
+                }
+                // no message is sent, but the view needs to be rebuilt
+                messages.push(Message::None);
+                return event::Status::Captured;
+            },
+            _ => {},
+        }
+
+        self.delegate(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !*self.is_open || self.matches.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let menu = Menu::new(
+            self.menu,
+            &self.matches,
+            self.hovered_option,
+            self.last_selection,
+        )
+        .width(bounds.width.round() as u16)
+        .padding(self.builder.padding);
+        let menu = match self.builder.font {
+            Some(font) => menu.font(font),
+            None => menu,
+        };
+        let menu = match self.builder.size {
+            Some(size) => menu.text_size(size),
+            None => menu,
+        };
+        let menu = match self.builder.menu_style {
+            Some(style) => menu.style(style.0),
+            None => menu,
+        };
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
@@ -0,0 +1,739 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::pick_list::StyleSheet;
+use iced::{
+    Background, Color, Element, Font, HorizontalAlignment, Length, Point, Rectangle, Size,
+    VerticalAlignment,
+};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{
+    event, keyboard, mouse, overlay, Clipboard, Event, Hasher, Layout, Overlay, Widget,
+};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDate, PyDateAccess};
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{DatePickerState, DateState, WrappedDatePickerState};
+use crate::styles::{PickListStyleSheet, WrappedPickListStyleSheet};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedLength};
+use crate::{format_to_string_ignore, make_with_state};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_date_picker, m)?)?;
+    Ok(())
+}
+
+/// Year, month and day.
+type Ymd = (i32, u8, u8);
+
+const MIN_DATE: Ymd = (1, 1, 1);
+const MAX_DATE: Ymd = (9999, 12, 31);
+
+const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Territories where the week starts on Sunday, according to the Unicode CLDR.
+const SUNDAY_FIRST: &[&str] = &[
+    "AG", "AS", "AU", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT",
+    "GU", "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT",
+    "MX", "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT",
+    "TW", "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+/// Territories where the week starts on Saturday, according to the Unicode CLDR.
+const SATURDAY_FIRST: &[&str] = &[
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct DatePickerBuilder {
+    pub state: DatePickerState,
+    pub token: Py<PyAny>,
+    pub date: Option<Ymd>,
+    pub label: String,
+    pub min: Ymd,
+    pub max: Ymd,
+    pub today: Ymd,
+    pub first_weekday: u8,
+    pub weekday_names: Vec<String>,
+    pub month_names: Vec<String>,
+    pub width: Length,
+    pub padding: u16,
+    pub text_size: Option<u16>,
+    pub font: Font,
+    pub style: Option<PickListStyleSheet>,
+}
+
+impl GCProtocol for DatePickerBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "date_picker")]
+/// date_picker($module, /, token, state, date, *, min=None, max=None, first_weekday=None, format=None, placeholder="", weekday_names=None, month_names=None, width=None, padding=None, text_size=None, font=None, style=None)
+/// --
+///
+/// A field to select a date in a calendar.
+///
+/// A click on the field opens a calendar. The arrows in its header (or the mouse wheel) show
+/// the previous or next month, the double arrows the previous or next year.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user selects a date, a message ``(token, new_date)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// state : DatePickerState
+///     Current state of the date picker. The same object must be given between calls.
+/// date : Optional[datetime.date]
+///     The currently selected date.
+/// min : Optional[datetime.date]
+///     The earliest selectable date.
+/// max : Optional[datetime.date]
+///     The latest selectable date.
+/// first_weekday : Optional[int]
+///     The first column of the calendar, 0 is Monday and 6 is Sunday, like in :func:`calendar.setfirstweekday()`.
+///     By default it is derived from the territory of the locale in ``LC_ALL``, ``LC_TIME`` or ``LANG``,
+///     e.g. Sunday for "en_US.UTF-8", and Monday for "de_DE.UTF-8".
+/// format : Optional[str]
+///     The :meth:`~datetime.date.strftime()` format of the date in the field. Defaults to the ISO format.
+/// placeholder : str
+///     Text in the field if there is no date.
+/// weekday_names : Optional[Sequence[str]]
+///     Seven names to show above the days, starting with Monday. Defaults to "Mo", "Tu" …
+/// month_names : Optional[Sequence[str]]
+///     Twelve names to show in the header, starting with January. Defaults to the English names.
+/// width : Optional[Length]
+///     The width of the field. Defaults to Length.SHRINK.
+/// padding : Optional[int]
+///     The padding of the field and of the calendar. Defaults to 5.
+/// text_size : Optional[int]
+///     The text size of the field and of the calendar.
+/// font : Optional[Font]
+///     The font of the field and of the calendar.
+/// style : Optional[PickListStyleSheet]
+///     The style of the field. The calendar uses its "menu" style.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created date picker.
+///
+/// Raises
+/// ------
+/// ValueError
+///     ``min`` is after ``max``, or an argument is out of range.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return date_picker('due', self.__due_state, self.__due, min=date.today())
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('due', value):
+///                 self.__due = value
+///
+/// See also
+/// --------
+/// :func:`~pyiced.time_picker()`
+fn make_date_picker(
+    py: Python,
+    token: Py<PyAny>,
+    state: &WrappedDatePickerState,
+    date: Option<&PyDate>,
+    min: Option<&PyDate>,
+    max: Option<&PyDate>,
+    first_weekday: Option<u8>,
+    format: Option<&str>,
+    placeholder: Option<String>,
+    weekday_names: Option<Vec<String>>,
+    month_names: Option<Vec<String>>,
+    width: Option<&WrappedLength>,
+    padding: Option<u16>,
+    text_size: Option<u16>,
+    font: Option<&WrappedFont>,
+    style: Option<&WrappedPickListStyleSheet>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let ymd = |date: &PyDate| (date.get_year(), date.get_month(), date.get_day());
+    let min = min.map_or(MIN_DATE, ymd);
+    let max = max.map_or(MAX_DATE, ymd);
+    if min > max {
+        return Err(PyErr::new::<PyValueError, _>(
+            "Expected 'min' to be less than or equal to 'max'",
+        ));
+    }
+
+    let first_weekday = match first_weekday {
+        Some(day @ 0..=6) => day,
+        Some(_) => {
+            return Err(PyErr::new::<PyValueError, _>(
+                "Expected 'first_weekday' to be in 0..=6",
+            ));
+        },
+        None => locale_first_weekday(),
+    };
+    let names = |names: Option<Vec<String>>, default: &[&str], message: &'static str| match names {
+        Some(names) if names.len() == default.len() => Ok(names),
+        Some(_) => Err(PyErr::new::<PyValueError, _>(message)),
+        None => Ok(default.iter().map(|&s| s.to_owned()).collect()),
+    };
+    let weekday_names = names(weekday_names, &WEEKDAY_NAMES, "Expected 7 'weekday_names'")?;
+    let month_names = names(month_names, &MONTH_NAMES, "Expected 12 'month_names'")?;
+
+    let label = match (date, format) {
+        (Some(date), Some(format)) => date.call_method1("strftime", (format,))?.str()?.to_string(),
+        (Some(date), None) => date.call_method0("isoformat")?.str()?.to_string(),
+        (None, _) => placeholder.unwrap_or_default(),
+    };
+    let today = py
+        .import("datetime")?
+        .getattr("date")?
+        .call_method0("today")?
+        .downcast::<PyDate>()?;
+
+    let el = DatePickerBuilder {
+        state: state.0.clone(),
+        token,
+        date: date.map(ymd),
+        label,
+        min,
+        max,
+        today: ymd(today),
+        first_weekday,
+        weekday_names,
+        month_names,
+        width: width.map_or(Length::Shrink, |o| o.0),
+        padding: padding.unwrap_or(5),
+        text_size,
+        font: font.map_or(Font::Default, |o| o.0),
+        style: style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+/// 0 = Monday, like Python's `calendar` module.
+fn locale_first_weekday() -> u8 {
+    let locale = ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+    // e.g. "en_US.UTF-8" or "sr_RS@latin"
+    let territory = locale
+        .split(['.', '@'])
+        .next()
+        .and_then(|s| s.split('_').nth(1))
+        .unwrap_or_default();
+    if SUNDAY_FIRST.contains(&territory) {
+        6
+    } else if SATURDAY_FIRST.contains(&territory) {
+        5
+    } else {
+        0
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 0 = Monday, like Python's `date.weekday()`.
+fn weekday(year: i32, month: u8, day: u8) -> u8 {
+    // Sakamoto's method, which counts from Sunday
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let sunday_based =
+        (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day as i32)
+            .rem_euclid(7);
+    ((sunday_based + 6) % 7) as u8
+}
+
+fn add_months((year, month): (i32, u8), months: i32) -> (i32, u8) {
+    let index = year * 12 + (month as i32 - 1) + months;
+    (index.div_euclid(12), (index.rem_euclid(12) + 1) as u8)
+}
+
+impl DatePickerBuilder {
+    fn text_size(&self, renderer: &Renderer) -> u16 {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn style(&self) -> Box<dyn StyleSheet> {
+        match self.style {
+            Some(style) => Box::new(style),
+            None => Default::default(),
+        }
+    }
+
+    /// Whether any day of the month can be selected.
+    fn is_month_selectable(&self, (year, month): (i32, u8)) -> bool {
+        (year, month) >= (self.min.0, self.min.1) && (year, month) <= (self.max.0, self.max.1)
+    }
+
+    fn is_selectable(&self, date: Ymd) -> bool {
+        (self.min..=self.max).contains(&date)
+    }
+
+    /// The month to show when the calendar is opened.
+    fn initial_month(&self) -> (i32, u8) {
+        let (year, month, day) = self.date.unwrap_or(self.today);
+        let (year, month, _) = (year, month, day).clamp(self.min, self.max);
+        (year, month)
+    }
+}
+
+impl ToNative for DatePickerBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        date_picker_with_state(&self.state, |state| {
+            Ok(DatePicker {
+                state,
+                builder: self.clone(),
+            })
+        })
+    }
+}
+
+make_with_state! {
+    date_picker_with_state(
+        crate::widgets::date_picker::DatePicker,
+        crate::widgets::date_picker::DatePicker<'this>,
+        crate::states::DateState,
+    );
+}
+
+pub(crate) struct DatePicker<'a> {
+    state: &'a mut DateState,
+    builder: DatePickerBuilder,
+}
+
+impl Widget<Message, Renderer> for DatePicker<'_> {
+    fn width(&self) -> Length {
+        self.builder.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let padding = self.builder.padding as f32;
+        let text_size = self.builder.text_size(renderer);
+        let limits = limits
+            .width(self.builder.width)
+            .height(Length::Shrink)
+            .pad(padding);
+
+        let width = match self.builder.width {
+            Length::Shrink => {
+                let (width, _) = renderer.measure(
+                    &self.builder.label,
+                    text_size,
+                    self.builder.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+                // the calendar should fit below the field
+                let calendar = 14.0 * text_size as f32;
+                (width.round() + text_size as f32 + padding).max(calendar - 2.0 * padding)
+            },
+            _ => 0.0,
+        };
+        let size = limits.resolve(Size::new(width, text_size as f32));
+        Node::new(size.pad(padding))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let text_size = self.builder.text_size(renderer);
+        iced_native::widget::pick_list::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            cursor_position,
+            Some(self.builder.label.clone()),
+            self.builder.padding,
+            text_size,
+            self.builder.font,
+            &self.builder.style(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.builder.width.hash(state);
+        self.builder.padding.hash(state);
+        self.builder.text_size.hash(state);
+        if let Length::Shrink = self.builder.width {
+            self.builder.label.hash(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if self.state.is_open {
+                    // a click on the calendar was already handled by the overlay
+                    self.state.is_open = cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                    event::Status::Captured
+                } else if layout.bounds().contains(cursor_position) {
+                    self.state.is_open = true;
+                    self.state.shown = self.builder.initial_month();
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: KeyCode::Escape,
+                ..
+            }) if self.state.is_open => {
+                self.state.is_open = false;
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+        let calendar = Calendar {
+            state: self.state,
+            builder: &self.builder,
+            target_height: layout.bounds().height,
+        };
+        Some(overlay::Element::new(layout.position(), Box::new(calendar)))
+    }
+}
+
+/// The overlay of an open date picker.
+struct Calendar<'a> {
+    state: &'a mut DateState,
+    builder: &'a DatePickerBuilder,
+    target_height: f32,
+}
+
+/// A cell in the calendar. The header row contains the navigation buttons and the title.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Months(i32),
+    Title,
+    WeekdayName(usize),
+    Day(u8),
+    Empty,
+}
+
+impl Calendar<'_> {
+    fn cell_size(&self, renderer: &Renderer) -> f32 {
+        2.0 * self.builder.text_size(renderer) as f32
+    }
+
+    fn cell(&self, column: usize, row: usize) -> Cell {
+        let (year, month) = self.state.shown;
+        match (row, column) {
+            (0, 0) => Cell::Months(-12),
+            (0, 1) => Cell::Months(-1),
+            (0, 5) => Cell::Months(1),
+            (0, 6) => Cell::Months(12),
+            (0, _) => Cell::Title,
+            (1, column) => Cell::WeekdayName((column + self.builder.first_weekday as usize) % 7),
+            (row, column) => {
+                let first = weekday(year, month, 1) as usize;
+                let skipped = (first + 7 - self.builder.first_weekday as usize) % 7;
+                let day = (row - 2) * 7 + column + 1;
+                match day.checked_sub(skipped) {
+                    Some(day) if day >= 1 && day <= days_in_month(year, month) as usize => {
+                        Cell::Day(day as u8)
+                    },
+                    _ => Cell::Empty,
+                }
+            },
+        }
+    }
+
+    fn cell_at(&self, layout: Layout<'_>, cell_size: f32, point: Point) -> Option<Cell> {
+        let padding = self.builder.padding as f32;
+        let bounds = layout.bounds();
+        let x = point.x - bounds.x - padding;
+        let y = point.y - bounds.y - padding;
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+        let (column, row) = ((x / cell_size) as usize, (y / cell_size) as usize);
+        (column < 7 && row < 8).then(|| self.cell(column, row))
+    }
+
+    fn is_enabled(&self, cell: Cell) -> bool {
+        let (year, month) = self.state.shown;
+        match cell {
+            Cell::Months(months) => {
+                let shown = add_months(self.state.shown, months);
+                (MIN_DATE.0..=MAX_DATE.0).contains(&shown.0)
+                    && self.builder.is_month_selectable(shown)
+            },
+            Cell::Day(day) => self.builder.is_selectable((year, month, day)),
+            Cell::Title | Cell::WeekdayName(_) | Cell::Empty => false,
+        }
+    }
+
+    fn show_months(&mut self, months: i32) {
+        let shown = add_months(self.state.shown, months);
+        let (min, max) = (self.builder.min, self.builder.max);
+        self.state.shown = shown.clamp((min.0, min.1), (max.0, max.1));
+    }
+}
+
+impl Overlay<Message, Renderer> for Calendar<'_> {
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let padding = self.builder.padding as f32;
+        let cell_size = self.cell_size(renderer);
+        let size = Size::new(
+            7.0 * cell_size + 2.0 * padding,
+            8.0 * cell_size + 2.0 * padding,
+        );
+
+        let space_below = bounds.height - (position.y + self.target_height);
+        let space_above = position.y;
+        let y = match space_below >= size.height || space_below > space_above {
+            true => position.y + self.target_height,
+            false => position.y - size.height,
+        };
+        let x = position.x.min(bounds.width - size.width).max(0.0);
+
+        let mut node = Node::new(size);
+        node.move_to(Point::new(x, y));
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> (Primitive, mouse::Interaction) {
+        let style = self.builder.style().menu();
+        let bounds = layout.bounds();
+        let padding = self.builder.padding as f32;
+        let cell_size = self.cell_size(renderer);
+        let text_size = self.builder.text_size(renderer) as f32;
+        let hovered = self
+            .cell_at(layout, cell_size, cursor_position)
+            .filter(|&cell| bounds.contains(cursor_position) && self.is_enabled(cell));
+        let (year, month) = self.state.shown;
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: 0.0,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+        let text = |content: String, bounds: Rectangle, color: Color| Primitive::Text {
+            content,
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            color,
+            size: text_size,
+            font: self.builder.font,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+        let faded = |color: Color, alpha: f32| Color {
+            a: color.a * alpha,
+            ..color
+        };
+
+        for row in 0..8 {
+            for column in 0..7 {
+                let cell = self.cell(column, row);
+                let cell_bounds = Rectangle {
+                    x: bounds.x + padding + column as f32 * cell_size,
+                    y: bounds.y + padding + row as f32 * cell_size,
+                    width: cell_size,
+                    height: cell_size,
+                };
+                let is_selected = match cell {
+                    Cell::Day(day) => self.builder.date == Some((year, month, day)),
+                    _ => false,
+                };
+                if is_selected || hovered == Some(cell) {
+                    primitives.push(Primitive::Quad {
+                        bounds: cell_bounds,
+                        background: match is_selected {
+                            true => style.selected_background,
+                            false => Background::Color(Color::TRANSPARENT),
+                        },
+                        border_radius: cell_size / 2.0,
+                        border_width: 1.0,
+                        border_color: match style.selected_background {
+                            Background::Color(color) => color,
+                        },
+                    });
+                }
+
+                let color = match (is_selected, self.is_enabled(cell)) {
+                    (true, _) => style.selected_text_color,
+                    (false, true) => style.text_color,
+                    (false, false) => faded(style.text_color, 0.4),
+                };
+                match cell {
+                    Cell::Months(months) => {
+                        let label = match months {
+                            -12 => "«",
+                            -1 => "‹",
+                            1 => "›",
+                            _ => "»",
+                        };
+                        primitives.push(text(label.to_owned(), cell_bounds, color));
+                    },
+                    Cell::Title if column == 3 => {
+                        let month_name = &self.builder.month_names[month as usize - 1];
+                        let title = format_to_string_ignore!("{} {}", month_name, year);
+                        let title = title.into_owned();
+                        primitives.push(text(title, cell_bounds, style.text_color));
+                    },
+                    Cell::WeekdayName(weekday) => {
+                        let name = self.builder.weekday_names[weekday].clone();
+                        primitives.push(text(name, cell_bounds, faded(style.text_color, 0.7)));
+                    },
+                    Cell::Day(day) => {
+                        if !is_selected && self.builder.today == (year, month, day) {
+                            primitives.push(Primitive::Quad {
+                                bounds: cell_bounds,
+                                background: Background::Color(Color::TRANSPARENT),
+                                border_radius: cell_size / 2.0,
+                                border_width: 1.0,
+                                border_color: faded(style.text_color, 0.4),
+                            });
+                        }
+                        primitives.push(text(day.to_string(), cell_bounds, color));
+                    },
+                    Cell::Title | Cell::Empty => {},
+                }
+            }
+        }
+
+        let interaction = match hovered {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.builder.padding.hash(state);
+        self.builder.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if !layout.bounds().contains(cursor_position) {
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let cell_size = self.cell_size(renderer);
+                match self.cell_at(layout, cell_size, cursor_position) {
+                    Some(cell @ Cell::Months(months)) if self.is_enabled(cell) => {
+                        self.show_months(months);
+                    },
+                    Some(cell @ Cell::Day(day)) if self.is_enabled(cell) => {
+                        let (year, month) = self.state.shown;
+                        let message = Python::with_gil(|py| {
+                            let date = PyDate::new(py, year, month, day)?;
+                            PyResult::Ok((&self.builder.token, date).into_py(py))
+                        });
+                        match message {
+                            Ok(message) => messages.push(Message::Python(message)),
+                            Err(err) => Python::with_gil(|py| err.print(py)),
+                        }
+                        self.state.is_open = false;
+                    },
+                    _ => {},
+                }
+                event::Status::Captured
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let months = match y {
+                    y if y > 0.0 => -1,
+                    y if y < 0.0 => 1,
+                    _ => return event::Status::Ignored,
+                };
+                if self.is_enabled(Cell::Months(months)) {
+                    self.show_months(months);
+                }
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use iced::Element;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::plugin::factory_from_capsule;
+use crate::widgets::WrappedWidgetBuilder;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_external, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ExternalBuilder {
+    pub factory: Py<PyAny>,
+}
+
+impl GCProtocol for ExternalBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.factory)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "external")]
+/// external($module, /, factory)
+/// --
+///
+/// A widget that is implemented in a third-party extension module.
+///
+/// The extension module has to be written in Rust, and it has to depend on pyiced's crate
+/// with the feature "plugin" enabled. See ``src/plugin.rs`` for more information.
+///
+/// Parameters
+/// ----------
+/// factory : object
+///     A ``PyCapsule`` named ``"pyiced.WidgetFactory"``, as returned by the extension module.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created element.
+///
+/// Raises
+/// ------
+/// TypeError
+///     The argument was not a widget factory.
+/// ValueError
+///     The extension module was compiled for a different version of pyiced or rustc.
+fn make_external(factory: &PyAny) -> PyResult<WrappedWidgetBuilder> {
+    let _ = factory_from_capsule(factory)?;
+    let el = ExternalBuilder {
+        factory: factory.into(),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for ExternalBuilder {
+    fn to_native(&self, py: Python) -> Element<'static, Message> {
+        match factory_from_capsule(self.factory.as_ref(py)) {
+            Ok(factory) => factory.to_native(py),
+            Err(err) => {
+                err.print(py);
+                crate::common::empty_space()
+            },
+        }
+    }
+}
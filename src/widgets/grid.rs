@@ -0,0 +1,556 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::{
+    Align, Element, HorizontalAlignment, Length, Point, Rectangle, Size, VerticalAlignment,
+};
+use iced_native::layout::{Limits, Node};
+use iced_native::{event, mouse, overlay, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{debug_str, EitherPy, GCProtocol, Message, ToNative};
+use crate::widgets::{WidgetBuilder, WrappedWidgetBuilder};
+use crate::wrapped::{
+    WrappedAlign, WrappedHorizontalAlignment, WrappedLength, WrappedVerticalAlignment,
+};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedGridCell>()?;
+    m.add_function(wrap_pyfunction!(make_grid, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GridBuilder {
+    pub children: Vec<GridCell>,
+    pub columns: Vec<Length>,
+    pub rows: Vec<Length>,
+    pub spacing: Option<u16>,
+    pub padding: Option<u16>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub align_x: Option<Align>,
+    pub align_y: Option<Align>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GridCell {
+    pub content: WidgetBuilder,
+    pub row: usize,
+    pub column: usize,
+    pub row_span: usize,
+    pub column_span: usize,
+    pub align_x: Option<Align>,
+    pub align_y: Option<Align>,
+}
+
+impl GCProtocol for GridBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        for cell in self.children.iter() {
+            cell.content.traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn align_x(value: EitherPy<WrappedAlign, WrappedHorizontalAlignment>) -> Align {
+    match value {
+        EitherPy::Left(WrappedAlign(align)) => align,
+        EitherPy::Right(WrappedHorizontalAlignment(align)) => match align {
+            HorizontalAlignment::Left => Align::Start,
+            HorizontalAlignment::Center => Align::Center,
+            HorizontalAlignment::Right => Align::End,
+        },
+    }
+}
+
+pub(crate) fn align_y(value: EitherPy<WrappedAlign, WrappedVerticalAlignment>) -> Align {
+    match value {
+        EitherPy::Left(WrappedAlign(align)) => align,
+        EitherPy::Right(WrappedVerticalAlignment(align)) => match align {
+            VerticalAlignment::Top => Align::Start,
+            VerticalAlignment::Center => Align::Center,
+            VerticalAlignment::Bottom => Align::End,
+        },
+    }
+}
+
+/// GridCell(content, row, column, *, row_span=1, column_span=1, align_x=None, align_y=None)
+/// --
+///
+/// The placement of an element inside a :func:`~pyiced.grid()`.
+///
+/// Parameters
+/// ----------
+/// content : Element
+///     The element to place.
+/// row : int
+///     Index of the first row of the cell, starting at 0.
+/// column : int
+///     Index of the first column of the cell, starting at 0.
+/// row_span : int
+///     Number of rows the cell spans.
+/// column_span : int
+///     Number of columns the cell spans.
+/// align_x : Union[Align, HorizontalAlignment, None]
+///     Horizontal alignment of the content inside the cell. Defaults to the grid's "align_x".
+/// align_y : Union[Align, VerticalAlignment, None]
+///     Vertical alignment of the content inside the cell. Defaults to the grid's "align_y".
+#[pyclass(name = "GridCell", module = "pyiced")]
+#[derive(Debug, Clone)]
+pub(crate) struct WrappedGridCell(pub GridCell);
+
+#[pymethods]
+impl WrappedGridCell {
+    #[args(row_span = "1", column_span = "1", align_x = "None", align_y = "None")]
+    #[new]
+    fn new(
+        content: &WrappedWidgetBuilder,
+        row: usize,
+        column: usize,
+        row_span: usize,
+        column_span: usize,
+        align_x: Option<EitherPy<WrappedAlign, WrappedHorizontalAlignment>>,
+        align_y: Option<EitherPy<WrappedAlign, WrappedVerticalAlignment>>,
+    ) -> PyResult<Self> {
+        if row_span == 0 || column_span == 0 {
+            return Err(PyErr::new::<PyValueError, _>("A span must be at least 1"));
+        }
+        Ok(Self(GridCell {
+            content: content.0.clone(),
+            row,
+            column,
+            row_span,
+            column_span,
+            align_x: align_x.map(self::align_x),
+            align_y: align_y.map(self::align_y),
+        }))
+    }
+
+    fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        self.0.content.traverse(&visit)
+    }
+
+    fn __clear__(&mut self) {
+        self.0.content = Default::default();
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
+
+fn extract_lengths(tracks: Option<&PyAny>) -> PyResult<Vec<Length>> {
+    let tracks = match tracks {
+        Some(tracks) => tracks,
+        None => return Ok(Vec::new()),
+    };
+    tracks
+        .iter()?
+        .map(|track| Ok(track?.extract::<WrappedLength>()?.0))
+        .collect()
+}
+
+#[pyfunction(name = "grid")]
+/// grid($module, /, children, *, columns=None, rows=None, spacing=None, padding=None, width=None, height=None, align_x=None, align_y=None)
+/// --
+///
+/// A container that distributes its contents in rows and columns.
+///
+/// The size of the tracks (columns and rows) follows their :class:`~pyiced.Length`:
+///
+/// * ``Length.units(i)`` tracks have a fixed size.
+/// * ``Length.SHRINK`` tracks are as big as their biggest content.
+///   Cells that span multiple tracks are not considered.
+/// * ``Length.FILL`` and ``Length.fill_portion(i)`` tracks share the remaining space.
+///
+/// Parameters
+/// ----------
+/// children : Iterable[Union[None, Element, GridCell]]
+///     The contents of the grid.
+///     Elements that are not wrapped in a :class:`~pyiced.GridCell` are put in the next free cell,
+///     going left-to-right, top-to-bottom. Cells occupied by a :class:`~pyiced.GridCell` are skipped.
+/// columns : Optional[Iterable[Length]]
+///     Widths of the columns.
+///     Only the number of given columns is used to place elements that are not wrapped in a
+///     :class:`~pyiced.GridCell`. Without "columns", they are put below each other in a single column.
+///     If there are more columns than given, the missing columns default to ``Length.FILL``.
+/// rows : Optional[Iterable[Length]]
+///     Heights of the rows.
+///     If there are more rows than given, the missing rows default to ``Length.SHRINK``.
+/// spacing : Optional[int]
+///     Horizontal and vertical spacing between the cells.
+/// padding : Optional[int]
+///     Padding of the grid.
+/// width : Optional[Length]
+///     Width of the grid.
+/// height : Optional[Length]
+///     Height of the grid.
+/// align_x : Union[Align, HorizontalAlignment, None]
+///     Default horizontal alignment of the contents in their cells. Defaults to "start".
+/// align_y : Union[Align, VerticalAlignment, None]
+///     Default vertical alignment of the contents in their cells. Defaults to "start".
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created grid.
+fn make_grid(
+    py: Python,
+    children: &PyAny,
+    columns: Option<&PyAny>,
+    rows: Option<&PyAny>,
+    spacing: Option<u16>,
+    padding: Option<u16>,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+    align_x: Option<EitherPy<WrappedAlign, WrappedHorizontalAlignment>>,
+    align_y: Option<EitherPy<WrappedAlign, WrappedVerticalAlignment>>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let mut columns = extract_lengths(columns)?;
+    let mut rows = extract_lengths(rows)?;
+
+    let column_count = columns.len().max(1);
+
+    // (cell, is_auto_placed)
+    let mut children: Vec<(GridCell, bool)> = children
+        .iter()?
+        .filter_map(|child| {
+            let child = match child {
+                Ok(child) if !child.is_none() => child,
+                Ok(_) => return None,
+                Err(err) => {
+                    err.print(py);
+                    return None;
+                },
+            };
+            if let Ok(WrappedGridCell(cell)) = child.extract() {
+                return Some((cell, false));
+            }
+            match child.extract() {
+                Ok(WrappedWidgetBuilder(content)) => {
+                    let cell = GridCell {
+                        content,
+                        row_span: 1,
+                        column_span: 1,
+                        ..Default::default()
+                    };
+                    Some((cell, true))
+                },
+                Err(err) => {
+                    err.print(py);
+                    None
+                },
+            }
+        })
+        .collect();
+
+    let mut extend_tracks = |cell: &GridCell| {
+        let column_end = cell.column.saturating_add(cell.column_span);
+        let row_end = cell.row.saturating_add(cell.row_span);
+        if column_end > u16::MAX as usize || row_end > u16::MAX as usize {
+            return Err(PyErr::new::<PyValueError, _>("Too many rows or columns"));
+        }
+        if columns.len() < column_end {
+            columns.resize(column_end, Length::Fill);
+        }
+        if rows.len() < row_end {
+            rows.resize(row_end, Length::Shrink);
+        }
+        Ok(())
+    };
+
+    for (cell, _) in children.iter().filter(|(_, auto)| !auto) {
+        extend_tracks(cell)?;
+    }
+
+    let mut next_cell = 0;
+    for index in 0..children.len() {
+        if !children[index].1 {
+            continue;
+        }
+        // skip the cells that are occupied by explicitly placed elements
+        let (row, column) = loop {
+            let (row, column) = (next_cell / column_count, next_cell % column_count);
+            let occupied_by = children.iter().find(|(cell, auto)| {
+                !auto
+                    && (cell.row..cell.row + cell.row_span).contains(&row)
+                    && (cell.column..cell.column + cell.column_span).contains(&column)
+            });
+            match occupied_by {
+                Some((cell, _)) => {
+                    next_cell =
+                        row * column_count + (cell.column + cell.column_span).min(column_count);
+                },
+                None => break (row, column),
+            }
+        };
+        next_cell += 1;
+
+        let cell = &mut children[index].0;
+        cell.row = row;
+        cell.column = column;
+        extend_tracks(cell)?;
+    }
+
+    let children = children.into_iter().map(|(cell, _)| cell).collect();
+
+    let el = GridBuilder {
+        children,
+        columns,
+        rows,
+        spacing,
+        padding,
+        width: width.map(|o| o.0),
+        height: height.map(|o| o.0),
+        align_x: align_x.map(self::align_x),
+        align_y: align_y.map(self::align_y),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for GridBuilder {
+    fn to_native(&self, py: Python) -> Element<'static, Message> {
+        let default_x = self.align_x.unwrap_or(Align::Start);
+        let default_y = self.align_y.unwrap_or(Align::Start);
+        let cells = self
+            .children
+            .iter()
+            .map(|cell| Cell {
+                element: cell.content.to_native(py),
+                row: cell.row,
+                column: cell.column,
+                row_span: cell.row_span,
+                column_span: cell.column_span,
+                align_x: cell.align_x.unwrap_or(default_x),
+                align_y: cell.align_y.unwrap_or(default_y),
+            })
+            .collect();
+        Element::new(Grid {
+            cells,
+            columns: self.columns.clone(),
+            rows: self.rows.clone(),
+            spacing: self.spacing.unwrap_or(0) as f32,
+            padding: self.padding.unwrap_or(0) as f32,
+            width: self.width.unwrap_or(Length::Shrink),
+            height: self.height.unwrap_or(Length::Shrink),
+        })
+    }
+}
+
+struct Cell {
+    element: Element<'static, Message>,
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    align_x: Align,
+    align_y: Align,
+}
+
+struct Grid {
+    cells: Vec<Cell>,
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    spacing: f32,
+    padding: f32,
+    width: Length,
+    height: Length,
+}
+
+/// Resolves the size of the tracks, and returns their offsets and sizes.
+fn resolve_tracks(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    shrink_size: impl Fn(usize) -> f32,
+) -> Vec<(f32, f32)> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .enumerate()
+        .map(|(index, track)| match track {
+            Length::Units(units) => *units as f32,
+            Length::Shrink => shrink_size(index),
+            Length::Fill | Length::FillPortion(_) if !available.is_finite() => shrink_size(index),
+            Length::Fill | Length::FillPortion(_) => 0.0,
+        })
+        .collect();
+
+    if available.is_finite() {
+        let total_spacing = spacing * tracks.len().saturating_sub(1) as f32;
+        let remaining = (available - total_spacing - sizes.iter().sum::<f32>()).max(0.0);
+        let portions: u32 = tracks.iter().map(|track| track.fill_factor() as u32).sum();
+        if portions > 0 {
+            for (size, track) in sizes.iter_mut().zip(tracks.iter()) {
+                let factor = track.fill_factor();
+                if factor > 0 {
+                    *size = (remaining * factor as f32 / portions as f32).floor();
+                }
+            }
+        }
+    }
+
+    let mut offset = 0.0;
+    sizes
+        .into_iter()
+        .map(|size| {
+            let result = (offset, size);
+            offset += size + spacing;
+            result
+        })
+        .collect()
+}
+
+fn span((start, _): (f32, f32), (end_offset, end_size): (f32, f32)) -> (f32, f32) {
+    (start, end_offset + end_size - start)
+}
+
+impl Widget<Message, Renderer> for Grid {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+        let max = limits.max();
+        let loose = Limits::new(Size::ZERO, max);
+
+        let columns = resolve_tracks(&self.columns, max.width, self.spacing, |index| {
+            self.cells
+                .iter()
+                .filter(|cell| cell.column == index && cell.column_span == 1)
+                .map(|cell| cell.element.layout(renderer, &loose).size().width)
+                .fold(0.0, f32::max)
+        });
+        let column_span = |cell: &Cell| {
+            span(
+                columns[cell.column],
+                columns[cell.column + cell.column_span - 1],
+            )
+        };
+
+        let rows = resolve_tracks(&self.rows, max.height, self.spacing, |index| {
+            self.cells
+                .iter()
+                .filter(|cell| cell.row == index && cell.row_span == 1)
+                .map(|cell| {
+                    let (_, width) = column_span(cell);
+                    let limits = Limits::new(Size::ZERO, Size::new(width, max.height));
+                    cell.element.layout(renderer, &limits).size().height
+                })
+                .fold(0.0, f32::max)
+        });
+        let row_span = |cell: &Cell| span(rows[cell.row], rows[cell.row + cell.row_span - 1]);
+
+        let nodes = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let (x, width) = column_span(cell);
+                let (y, height) = row_span(cell);
+                let space = Size::new(width, height);
+                let mut node = cell
+                    .element
+                    .layout(renderer, &Limits::new(Size::ZERO, space));
+                node.move_to(Point::new(x + self.padding, y + self.padding));
+                node.align(cell.align_x, cell.align_y, space);
+                node
+            })
+            .collect();
+
+        let content_size = Size::new(
+            columns.last().map_or(0.0, |&(offset, size)| offset + size),
+            rows.last().map_or(0.0, |&(offset, size)| offset + size),
+        );
+        let size = limits.resolve(content_size).pad(self.padding);
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let mut interaction = mouse::Interaction::default();
+        let primitives = self
+            .cells
+            .iter()
+            .zip(layout.children())
+            .map(|(cell, layout)| {
+                let (primitive, cell_interaction) =
+                    cell.element
+                        .draw(renderer, defaults, layout, cursor_position, viewport);
+                interaction = interaction.max(cell_interaction);
+                primitive
+            })
+            .collect();
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.columns.hash(state);
+        self.rows.hash(state);
+        self.spacing.to_bits().hash(state);
+        self.padding.to_bits().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        for cell in self.cells.iter() {
+            cell.row.hash(state);
+            cell.column.hash(state);
+            cell.row_span.hash(state);
+            cell.column_span.hash(state);
+            cell.align_x.hash(state);
+            cell.align_y.hash(state);
+            cell.element.hash_layout(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.cells
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(cell, layout)| {
+                cell.element.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.cells
+            .iter_mut()
+            .zip(layout.children())
+            .find_map(|(cell, layout)| cell.element.overlay(layout))
+    }
+}
@@ -1,12 +1,33 @@
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::hash::Hash;
+use std::sync::mpsc::{sync_channel, SyncSender};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+use futures_util::stream::{unfold, BoxStream};
 use iced::image::Handle;
-use iced::{Element, Image, Length};
+use iced::{svg, Color, Element, Image, Length, Point, Rectangle, Size, Subscription, Vector};
+use iced_native::image::Data;
+use iced_native::layout::{Limits, Node};
+use iced_native::subscription::Recipe;
+use iced_native::{mouse, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use image::imageops::FilterType;
+use image::{ImageBuffer, Rgba, RgbaImage};
+use parking_lot::{const_mutex, Mutex};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
+use tokio::sync::Notify;
 
 use crate::assign;
 use crate::common::{GCProtocol, Message, ToNative};
 use crate::widgets::WrappedWidgetBuilder;
-use crate::wrapped::{WrappedImageHandle, WrappedLength};
+use crate::wrapped::{
+    color_to_rgba8, parse_svg, rasterize_svg, PixelFormat, WrappedImageHandle, WrappedLength,
+};
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(make_image, m)?)?;
@@ -18,12 +39,13 @@ pub(crate) struct ImageBuilder {
     pub handle: Handle,
     pub width: Option<Length>,
     pub height: Option<Length>,
+    pub options: Option<PictureOptions>,
 }
 
 impl GCProtocol for ImageBuilder {}
 
 #[pyfunction(name = "image")]
-/// image($module, /, handle, *, width=None, height=None)
+/// image($module, /, handle, *, width=None, height=None, content_fit=None, filter=None, opacity=None, rotation=None)
 /// --
 ///
 /// A frame that displays an image while keeping aspect ratio.
@@ -36,12 +58,36 @@ impl GCProtocol for ImageBuilder {}
 ///     The width of the image.
 /// heigth : Optional[Length]
 ///     The height of the image.
+/// content_fit : Optional[str]
+///     How the image is fitted into the frame. The image is centered in the frame.
+///
+///     * "contain": scale the image to fit into the frame, keeping its aspect ratio (default)
+///     * "cover": scale the image to cover the whole frame, keeping its aspect ratio, and clip it
+///     * "fill": stretch the image to the size of the frame
+///     * "none": show the image in its natural size, and clip it
+///     * "scale_down": like "contain", but never scale the image up
+///
+///     Unlike the default layout, the frame keeps the size of width and height if content_fit is
+///     set, so that e.g. thumbnails of different aspect ratios line up in a grid.
+/// filter : Optional[str]
+///     How the image is sampled if it is scaled: "linear" (default) or "nearest", e.g. for pixel art.
+/// opacity : Optional[float]
+///     The opacity of the image between 0.0 and 1.0 (default).
+/// rotation : Optional[float]
+///     Clockwise rotation of the image in degrees.
+///     The natural size of the image is the bounding box of the rotated image.
 ///
 /// Returns
 /// -------
 /// Element
 ///     The newly created image element.
 ///
+/// Notes
+/// -----
+/// Filtering with "nearest", opacity and rotation are applied on the CPU in a background thread,
+/// so the image can appear with a short delay.
+/// The results are cached, but changing the values often is costly.
+///
 /// Example
 /// -------
 /// .. image:: ../examples/widgets/image.png
@@ -58,19 +104,651 @@ fn make_image(
     handle: &WrappedImageHandle,
     width: Option<&WrappedLength>,
     height: Option<&WrappedLength>,
-) -> WrappedWidgetBuilder {
+    content_fit: Option<&str>,
+    filter: Option<&str>,
+    opacity: Option<f32>,
+    rotation: Option<f32>,
+) -> PyResult<WrappedWidgetBuilder> {
     let el = ImageBuilder {
         handle: handle.0.clone(),
         width: width.map(|o| o.0),
         height: height.map(|o| o.0),
+        options: PictureOptions::new(content_fit, filter, opacity, rotation, None)?,
     };
-    el.into()
+    Ok(el.into())
 }
 
 impl ToNative for ImageBuilder {
     fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        if let Some(options) = self.options {
+            return Element::new(Picture {
+                source: Source::Image(self.handle.clone()),
+                options,
+                width: self.width.unwrap_or(Length::Shrink),
+                height: self.height.unwrap_or(Length::Shrink),
+            });
+        }
         let el = Image::new(self.handle.clone());
         let el = assign!(el, self, width, height);
         el.into()
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum ContentFit {
+    Contain,
+    Cover,
+    Fill,
+    None,
+    ScaleDown,
+}
+
+impl ContentFit {
+    /// The size of a picture with the natural size `size`, when drawn into `bounds`.
+    fn fit(self, size: Size, bounds: Size) -> Size {
+        let contain = (bounds.width / size.width).min(bounds.height / size.height);
+        let scale = match self {
+            Self::Fill => return bounds,
+            Self::None => 1.0,
+            Self::Contain => contain,
+            Self::ScaleDown => contain.min(1.0),
+            Self::Cover => (bounds.width / size.width).max(bounds.height / size.height),
+        };
+        match scale.is_finite() {
+            true => Size::new(size.width * scale, size.height * scale),
+            false => Size::ZERO,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum FilterMethod {
+    Linear,
+    Nearest,
+}
+
+/// Options of an :func:`image()` or :func:`svg()` that the renderer does not support natively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PictureOptions {
+    pub content_fit: ContentFit,
+    pub filter: FilterMethod,
+    pub opacity: f32,
+    pub rotation: f32,
+    pub color: Option<Color>,
+}
+
+impl PictureOptions {
+    /// Returns None if no option was supplied, so the native widget can be used.
+    pub(crate) fn new(
+        content_fit: Option<&str>,
+        filter: Option<&str>,
+        opacity: Option<f32>,
+        rotation: Option<f32>,
+        color: Option<Color>,
+    ) -> PyResult<Option<Self>> {
+        if content_fit.is_none()
+            && filter.is_none()
+            && opacity.is_none()
+            && rotation.is_none()
+            && color.is_none()
+        {
+            return Ok(None);
+        }
+
+        let content_fit = match content_fit {
+            None | Some("contain") => ContentFit::Contain,
+            Some("cover") => ContentFit::Cover,
+            Some("fill") => ContentFit::Fill,
+            Some("none") => ContentFit::None,
+            Some("scale_down") => ContentFit::ScaleDown,
+            Some(_) => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Expected 'content_fit' to be one of \"contain\", \"cover\", \"fill\", \
+                     \"none\" or \"scale_down\"",
+                ));
+            },
+        };
+        let filter = match filter {
+            None | Some("linear") => FilterMethod::Linear,
+            Some("nearest") => FilterMethod::Nearest,
+            Some(_) => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Expected 'filter' to be \"linear\" or \"nearest\"",
+                ));
+            },
+        };
+        let opacity = match opacity {
+            None => 1.0,
+            Some(opacity) if (0.0..=1.0).contains(&opacity) => opacity,
+            Some(_) => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Expected 'opacity' to be between 0.0 and 1.0",
+                ));
+            },
+        };
+        let rotation = match rotation {
+            None => 0.0,
+            Some(rotation) if rotation.is_finite() => rotation.rem_euclid(360.0),
+            Some(_) => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Expected 'rotation' to be a finite number",
+                ));
+            },
+        };
+        Ok(Some(Self {
+            content_fit,
+            filter,
+            opacity,
+            rotation,
+            color,
+        }))
+    }
+
+    /// True if the picture can be drawn by the renderer without preprocessing.
+    fn is_native(&self) -> bool {
+        self.filter == FilterMethod::Linear
+            && self.opacity >= 1.0
+            && self.rotation == 0.0
+            && self.color.is_none()
+    }
+
+    /// The bounding box of a picture of the given size after the rotation.
+    fn rotated(&self, size: Size) -> Size {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let (sin, cos) = (sin.abs(), cos.abs());
+        Size::new(
+            size.width * cos + size.height * sin,
+            size.width * sin + size.height * cos,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Source {
+    Image(Handle),
+    Svg(svg::Handle),
+}
+
+impl Source {
+    fn id(&self) -> (u8, u64) {
+        match self {
+            Self::Image(handle) => (0, handle.id()),
+            Self::Svg(handle) => (1, handle.id()),
+        }
+    }
+}
+
+/// An :func:`image()` or :func:`svg()` with [`PictureOptions`].
+pub(crate) struct Picture {
+    pub source: Source,
+    pub options: PictureOptions,
+    pub width: Length,
+    pub height: Length,
+}
+
+impl Picture {
+    fn natural_size(&self, renderer: &Renderer) -> Size {
+        let (width, height) = match &self.source {
+            Source::Image(handle) => {
+                iced_native::widget::image::Renderer::dimensions(renderer, handle)
+            },
+            Source::Svg(handle) => iced_native::widget::svg::Renderer::dimensions(renderer, handle),
+        };
+        self.options.rotated(Size::new(width as f32, height as f32))
+    }
+}
+
+impl Widget<Message, Renderer> for Picture {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        Node::new(limits.resolve(self.natural_size(renderer)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let bounds = layout.bounds();
+        let natural = self.natural_size(renderer);
+        let size = self.options.content_fit.fit(natural, bounds.size());
+        let rect = Rectangle {
+            x: bounds.x + (bounds.width - size.width) / 2.0,
+            y: bounds.y + (bounds.height - size.height) / 2.0,
+            width: size.width,
+            height: size.height,
+        };
+
+        let processed = match self.options.is_native() {
+            true => None,
+            false => match process(&self.source, &self.options, natural, size) {
+                Some(handle) => Some(handle),
+                None => return (Primitive::None, mouse::Interaction::default()),
+            },
+        };
+        let primitive = match (processed, &self.source) {
+            (Some(handle), _) => Primitive::Image {
+                handle,
+                bounds: rect,
+            },
+            (None, Source::Image(handle)) => Primitive::Image {
+                handle: handle.clone(),
+                bounds: rect,
+            },
+            (None, Source::Svg(handle)) => Primitive::Svg {
+                handle: handle.clone(),
+                bounds: rect,
+            },
+        };
+        let primitive = match rect.width > bounds.width || rect.height > bounds.height {
+            true => Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(primitive),
+            },
+            false => primitive,
+        };
+        (primitive, mouse::Interaction::default())
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.source.id().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.options.rotation.to_bits().hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct CacheKey {
+    source: (u8, u64),
+    filter: FilterMethod,
+    opacity: u32,
+    rotation: u32,
+    color: Option<[u32; 4]>,
+    scale: u32,
+}
+
+enum Processed {
+    Pending,
+    Failed,
+    Done { handle: Handle, bytes: usize },
+}
+
+/// Processed pictures. The least recently used pictures are dropped when the cache grows too big.
+struct Cache {
+    entries: BTreeMap<CacheKey, (Processed, u64)>,
+    bytes: usize,
+    clock: u64,
+    /// Modification times of the source files when they were last processed.
+    modified: BTreeMap<(u8, u64), Duration>,
+    /// Queue of the worker threads, started when the first picture is processed.
+    jobs: Option<SyncSender<Job>>,
+}
+
+static CACHE: Mutex<Cache> = const_mutex(Cache {
+    entries: BTreeMap::new(),
+    bytes: 0,
+    clock: 0,
+    modified: BTreeMap::new(),
+    jobs: None,
+});
+
+/// Notified when a picture was processed in the background.
+static PROCESSED: Notify = Notify::const_new();
+
+const CACHE_BYTES: usize = 128 << 20;
+
+const MAX_DIMENSION: f32 = 8192.0;
+
+const QUEUED_JOBS: usize = 64;
+
+struct Job {
+    key: CacheKey,
+    source: Source,
+    options: PictureOptions,
+    scale: f32,
+}
+
+impl Cache {
+    fn jobs(&mut self) -> &SyncSender<Job> {
+        self.jobs.get_or_insert_with(|| {
+            let (sender, receiver) = sync_channel::<Job>(QUEUED_JOBS);
+            let receiver = Arc::new(Mutex::new(receiver));
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            for _ in 0..threads {
+                let receiver = Arc::clone(&receiver);
+                std::thread::spawn(move || {
+                    loop {
+                        let job = receiver.lock().recv();
+                        match job {
+                            Ok(job) => job.run(),
+                            Err(_) => break,
+                        }
+                    }
+                });
+            }
+            sender
+        })
+    }
+
+    /// Drops the processed pictures of a source file if the file was changed since.
+    fn check_modified(&mut self, key: CacheKey, modified: Option<Duration>) {
+        let modified = match modified {
+            Some(modified) => modified,
+            None => return,
+        };
+        match self.modified.insert(key.source, modified) {
+            Some(previous) if previous != modified => {},
+            _ => return,
+        }
+        let stale: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(k, (p, _))| {
+                k.source == key.source && **k != key && !matches!(p, Processed::Pending)
+            })
+            .map(|(k, _)| *k)
+            .collect();
+        for k in stale {
+            if let Some((Processed::Done { bytes, .. }, _)) = self.entries.remove(&k) {
+                self.bytes -= bytes;
+            }
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, processed: Processed) {
+        if let Processed::Done { bytes, .. } = processed {
+            self.bytes += bytes;
+        }
+        self.clock += 1;
+        self.entries.insert(key, (processed, self.clock));
+
+        while self.bytes > CACHE_BYTES {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(k, (p, _))| **k != key && matches!(p, Processed::Done { .. }))
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(k, _)| *k);
+            match oldest.and_then(|k| self.entries.remove(&k)) {
+                Some((Processed::Done { bytes, .. }, _)) => self.bytes -= bytes,
+                _ => break,
+            }
+        }
+    }
+}
+
+/// The factor by which the picture is enlarged on the CPU.
+///
+/// SVGs are rendered in steps of a quarter of an octave, and nearest filtering uses integer
+/// factors, so that resizing the window only produces a few differently sized pictures.
+fn scale(source: &Source, filter: FilterMethod, natural: Size, size: Size) -> f32 {
+    let ratio = (size.width / natural.width).max(size.height / natural.height);
+    let max_ratio = MAX_DIMENSION / natural.width.max(natural.height);
+    let scale = match (source, filter) {
+        (Source::Image(_), FilterMethod::Linear) => return 1.0,
+        (_, FilterMethod::Nearest) => ratio.ceil().min(max_ratio.floor()).max(1.0),
+        (Source::Svg(_), FilterMethod::Linear) => {
+            ((ratio.log2() * 4.0).ceil() / 4.0).exp2().min(max_ratio)
+        },
+    };
+    match scale.is_finite() && scale > 0.0 {
+        true => scale,
+        false => 1.0,
+    }
+}
+
+/// The modification time of the source file. Only checked when a picture is processed.
+fn modified(source: &Source) -> Option<Duration> {
+    let path = match source {
+        Source::Image(handle) => match handle.data() {
+            Data::Path(path) => path,
+            _ => return None,
+        },
+        Source::Svg(handle) => match handle.data() {
+            iced_native::svg::Data::Path(path) => path,
+            _ => return None,
+        },
+    };
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    modified.duration_since(UNIX_EPOCH).ok()
+}
+
+/// Applies the options that the renderer does not support natively.
+///
+/// The picture is processed by a pool of background threads. Until it is ready, the picture is shown in
+/// another scale if possible. Returns None if the picture could not be decoded, or if there
+/// is nothing to show yet.
+fn process(source: &Source, options: &PictureOptions, natural: Size, size: Size) -> Option<Handle> {
+    if !(size.width >= 1.0 && size.height >= 1.0) {
+        return None;
+    }
+    let scale = self::scale(source, options.filter, natural, size);
+    let key = CacheKey {
+        source: source.id(),
+        filter: options.filter,
+        opacity: options.opacity.to_bits(),
+        rotation: options.rotation.to_bits(),
+        color: options
+            .color
+            .map(|c| [c.r, c.g, c.b, c.a].map(f32::to_bits)),
+        scale: scale.to_bits(),
+    };
+
+    let mut cache = CACHE.lock();
+    cache.clock += 1;
+    let clock = cache.clock;
+    match cache.entries.get_mut(&key) {
+        Some((Processed::Done { handle, .. }, used)) => {
+            *used = clock;
+            return Some(handle.clone());
+        },
+        Some((Processed::Failed, _)) => return None,
+        Some((Processed::Pending, _)) => {},
+        None => {
+            let job = Job {
+                key,
+                source: source.clone(),
+                options: *options,
+                scale,
+            };
+            // If the queue is full, the picture is requested again once a queued job is done.
+            if cache.jobs().try_send(job).is_ok() {
+                cache.entries.insert(key, (Processed::Pending, clock));
+            }
+        },
+    }
+
+    // show the picture in another scale while it is processed
+    let first = CacheKey { scale: 0, ..key };
+    let last = CacheKey {
+        scale: u32::MAX,
+        ..key
+    };
+    cache
+        .entries
+        .range(first..=last)
+        .find_map(|(_, (processed, _))| match processed {
+            Processed::Done { handle, .. } => Some(handle.clone()),
+            _ => None,
+        })
+}
+
+impl Job {
+    fn run(self) {
+        let modified = modified(&self.source);
+        let processed = match render(&self.source, &self.options, self.scale) {
+            Some(image) => {
+                let (width, height) = image.dimensions();
+                let pixels = PixelFormat::Rgba8.to_bgra(image.into_raw());
+                Processed::Done {
+                    bytes: pixels.len(),
+                    handle: Handle::from_pixels(width, height, pixels),
+                }
+            },
+            None => Processed::Failed,
+        };
+        let mut cache = CACHE.lock();
+        cache.check_modified(self.key, modified);
+        cache.insert(self.key, processed);
+        drop(cache);
+        PROCESSED.notify_one();
+    }
+}
+
+/// Wakes the app when a picture was processed, so that it is drawn.
+pub(crate) fn subscription() -> Subscription<Message> {
+    Subscription::from_recipe(ProcessedRecipe)
+}
+
+struct ProcessedRecipe;
+
+impl<H, E> Recipe<H, E> for ProcessedRecipe
+where
+    H: std::hash::Hasher,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut H) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<Self::Output> {
+        Box::pin(unfold((), |()| async {
+            PROCESSED.notified().await;
+            Some((Message::None, ()))
+        }))
+    }
+}
+
+fn render(source: &Source, options: &PictureOptions, scale: f32) -> Option<RgbaImage> {
+    let image = match source {
+        Source::Image(handle) => decode_image(handle)?,
+        Source::Svg(handle) => {
+            let zoom = match options.filter {
+                FilterMethod::Linear => scale,
+                FilterMethod::Nearest => 1.0,
+            };
+            rasterize_svg(&parse_svg(handle)?, zoom)?
+        },
+    };
+
+    let mut image = rotate(image, options.rotation, options.filter);
+    if options.filter == FilterMethod::Nearest && scale > 1.0 {
+        let factor = scale as u32;
+        let (width, height) = (image.width() * factor, image.height() * factor);
+        image = image::imageops::resize(&image, width, height, FilterType::Nearest);
+    }
+    if let Some(color) = options.color {
+        // keep the coverage of the image, but paint it in a single color
+        let [r, g, b, a] = color_to_rgba8(&color);
+        for pixel in image.pixels_mut() {
+            let alpha = (pixel[3] as u32 * a as u32 + 127) / 255;
+            *pixel = Rgba([r, g, b, alpha as u8]);
+        }
+    }
+    if options.opacity < 1.0 {
+        for pixel in image.pixels_mut() {
+            pixel[3] = (pixel[3] as f32 * options.opacity).round() as u8;
+        }
+    }
+    Some(image)
+}
+
+fn decode_image(handle: &Handle) -> Option<RgbaImage> {
+    let image = match handle.data() {
+        Data::Path(path) => image::open(path).ok()?.into_rgba8(),
+        Data::Bytes(bytes) => image::load_from_memory(bytes).ok()?.into_rgba8(),
+        Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => {
+            // BGRA -> RGBA is the same swap as RGBA -> BGRA
+            let pixels = PixelFormat::Rgba8.to_bgra(pixels.clone());
+            ImageBuffer::from_raw(*width, *height, pixels)?
+        },
+    };
+    Some(image)
+}
+
+/// Rotates the image clockwise, enlarging it to the bounding box of the rotated image.
+fn rotate(image: RgbaImage, degrees: f32, filter: FilterMethod) -> RgbaImage {
+    if degrees == 0.0 {
+        return image;
+    } else if degrees == 90.0 {
+        return image::imageops::rotate90(&image);
+    } else if degrees == 180.0 {
+        return image::imageops::rotate180(&image);
+    } else if degrees == 270.0 {
+        return image::imageops::rotate270(&image);
+    }
+
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let (src_width, src_height) = (image.width() as f32, image.height() as f32);
+    let width = (src_width * cos.abs() + src_height * sin.abs()).ceil();
+    let height = (src_width * sin.abs() + src_height * cos.abs()).ceil();
+    ImageBuffer::from_fn(width as u32, height as u32, |x, y| {
+        // map the center of the destination pixel back into the source image
+        let dx = x as f32 + 0.5 - width / 2.0;
+        let dy = y as f32 + 0.5 - height / 2.0;
+        let sx = dx * cos + dy * sin + src_width / 2.0;
+        let sy = -dx * sin + dy * cos + src_height / 2.0;
+        match filter {
+            FilterMethod::Nearest => pixel_at(&image, sx.floor(), sy.floor()),
+            FilterMethod::Linear => bilinear(&image, sx - 0.5, sy - 0.5),
+        }
+    })
+}
+
+fn pixel_at(image: &RgbaImage, x: f32, y: f32) -> Rgba<u8> {
+    if x < 0.0 || y < 0.0 || x >= image.width() as f32 || y >= image.height() as f32 {
+        return Rgba([0, 0, 0, 0]);
+    }
+    *image.get_pixel(x as u32, y as u32)
+}
+
+/// Samples the image between pixels, interpolating with premultiplied alpha.
+fn bilinear(image: &RgbaImage, x: f32, y: f32) -> Rgba<u8> {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let mut sum = [0.0f32; 4];
+    for (px, py, weight) in [
+        (x0, y0, (1.0 - fx) * (1.0 - fy)),
+        (x0 + 1.0, y0, fx * (1.0 - fy)),
+        (x0, y0 + 1.0, (1.0 - fx) * fy),
+        (x0 + 1.0, y0 + 1.0, fx * fy),
+    ] {
+        let Rgba([r, g, b, a]) = pixel_at(image, px, py);
+        let alpha = a as f32 * weight;
+        sum[0] += r as f32 * alpha;
+        sum[1] += g as f32 * alpha;
+        sum[2] += b as f32 * alpha;
+        sum[3] += alpha;
+    }
+    match sum[3] > 0.0 {
+        true => Rgba([
+            (sum[0] / sum[3]).round() as u8,
+            (sum[1] / sum[3]).round() as u8,
+            (sum[2] / sum[3]).round() as u8,
+            sum[3].round() as u8,
+        ]),
+        false => Rgba([0, 0, 0, 0]),
+    }
+}
@@ -0,0 +1,276 @@
+use std::any::TypeId;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use iced::image::Handle;
+use iced::{Element, Length, Point, Rectangle, Size, Vector};
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::image::Renderer as _;
+use iced_native::{event, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{ImageViewerState, Pending, WrappedImageViewerState};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedImageHandle, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_image_viewer, m)?)?;
+    Ok(())
+}
+
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone)]
+pub(crate) struct ImageViewerBuilder {
+    pub state: ImageViewerState,
+    pub handle: Handle,
+    pub min_scale: f32,
+    pub max_scale: f32,
+    pub scale_step: f32,
+    pub width: Length,
+    pub height: Length,
+}
+
+impl GCProtocol for ImageViewerBuilder {}
+
+#[pyfunction(name = "image_viewer")]
+/// image_viewer($module, /, state, handle, *, min_scale=0.25, max_scale=10.0, scale_step=0.1, width=None, height=None)
+/// --
+///
+/// A frame that displays an image, which can be zoomed and panned.
+///
+/// * Use the mouse wheel to zoom in and out around the cursor.
+/// * Drag the image to pan it.
+/// * Double-click to fit the image into the frame.
+///
+/// Parameters
+/// ----------
+/// state : ImageViewerState
+///     The current state of the viewer. Use it to query or set the zoom.
+/// handle : ImageHandle
+///     The handle of the image.
+/// min_scale : float
+///     The smallest zoom factor. Zooming out until the whole image is visible is always possible.
+/// max_scale : float
+///     The largest zoom factor.
+/// scale_step : float
+///     The relative zoom factor change for one step of the mouse wheel.
+/// width : Optional[Length]
+///     The width of the viewer. Defaults to Length.FILL.
+/// height : Optional[Length]
+///     The height of the viewer. Defaults to Length.FILL.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created image viewer.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return column([
+///             button(self.__fit_state, text('Fit'), ('fit', None)),
+///             image_viewer(self.__viewer_state, self.__micrograph, max_scale=32.0),
+///         ])
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('fit', _):
+///                 self.__viewer_state.fit()
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.image()`
+/// * :class:`~pyiced.ImageViewerState`
+fn make_image_viewer(
+    state: &WrappedImageViewerState,
+    handle: &WrappedImageHandle,
+    min_scale: Option<f32>,
+    max_scale: Option<f32>,
+    scale_step: Option<f32>,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let min_scale = min_scale.unwrap_or(0.25);
+    let max_scale = max_scale.unwrap_or(10.0);
+    let scale_step = scale_step.unwrap_or(0.1);
+    if !(min_scale.is_finite() && min_scale > 0.0 && max_scale.is_finite()) {
+        return Err(PyErr::new::<PyValueError, _>(
+            "Expected 'min_scale' and 'max_scale' to be positive",
+        ));
+    } else if min_scale > max_scale {
+        return Err(PyErr::new::<PyValueError, _>(
+            "Expected 'min_scale' to be less than or equal to 'max_scale'",
+        ));
+    } else if !(scale_step.is_finite() && scale_step > 0.0) {
+        return Err(PyErr::new::<PyValueError, _>(
+            "Expected 'scale_step' to be positive",
+        ));
+    }
+
+    let el = ImageViewerBuilder {
+        state: state.0.clone(),
+        handle: handle.0.clone(),
+        min_scale,
+        max_scale,
+        scale_step,
+        width: width.map_or(Length::Fill, |o| o.0),
+        height: height.map_or(Length::Fill, |o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for ImageViewerBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        Element::new(ImageViewer(self.clone()))
+    }
+}
+
+struct ImageViewer(ImageViewerBuilder);
+
+impl ImageViewer {
+    fn image_size(&self, renderer: &Renderer) -> Size {
+        let (width, height) = renderer.dimensions(&self.0.handle);
+        Size::new(width as f32, height as f32)
+    }
+}
+
+impl Widget<Message, Renderer> for ImageViewer {
+    fn width(&self) -> Length {
+        self.0.width
+    }
+
+    fn height(&self) -> Length {
+        self.0.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.0.width).height(self.0.height);
+        Node::new(limits.resolve(self.image_size(renderer)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let bounds = layout.bounds();
+        let image = self.image_size(renderer);
+
+        let mut state = self.0.state.write();
+        state.viewport = Some(bounds.size());
+        state.image = Some(image);
+        state.min_scale = self.0.min_scale;
+        state.max_scale = self.0.max_scale;
+        state.apply_pending();
+        state.clamp_offset();
+
+        let primitive = Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Image {
+                handle: self.0.handle.clone(),
+                bounds: Rectangle {
+                    x: bounds.x + state.offset.x,
+                    y: bounds.y + state.offset.y,
+                    width: image.width * state.scale,
+                    height: image.height * state.scale,
+                },
+            }),
+        };
+        let interaction = if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if bounds.contains(cursor_position) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        };
+        (primitive, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.0.width.hash(state);
+        self.0.height.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let relative = Point::new(cursor_position.x - bounds.x, cursor_position.y - bounds.y);
+        let mut state = self.0.state.write();
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+                let steps = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+                if steps == 0.0 {
+                    return event::Status::Ignored;
+                }
+                state.zoom((1.0 + self.0.scale_step).powf(steps), relative);
+                event::Status::Captured
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+                let now = Instant::now();
+                let double_click = match state.last_click {
+                    Some((time, position)) => {
+                        now.duration_since(time) < DOUBLE_CLICK
+                            && position.distance(cursor_position) < 4.0
+                    },
+                    None => false,
+                };
+                if double_click {
+                    state.last_click = None;
+                    state.drag = None;
+                    state.pending = Some(Pending::Fit);
+                    state.apply_pending();
+                } else {
+                    state.last_click = Some((now, cursor_position));
+                    state.drag = Some((cursor_position, state.offset));
+                }
+                event::Status::Captured
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => match state.drag {
+                Some((start, offset)) => {
+                    state.offset = offset + (cursor_position - start);
+                    state.clamp_offset();
+                    event::Status::Captured
+                },
+                None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                match state.drag.take() {
+                    Some(_) => event::Status::Captured,
+                    None => event::Status::Ignored,
+                }
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+}
@@ -0,0 +1,803 @@
+use std::any::TypeId;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use iced::scrollable::StyleSheet;
+use iced::{
+    Background, Color, Element, Font, HorizontalAlignment, Length, Point, Rectangle, Size, Vector,
+    VerticalAlignment,
+};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{event, keyboard, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PySet;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{ListBoxState, ListState, WrappedListBoxState};
+use crate::styles::{
+    ListBoxStyle, ScrollableStyleSheet, WrappedListBoxStyle, WrappedScrollableStyleSheet,
+};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedLength};
+use crate::{format_to_string_ignore, make_with_state};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_list_box, m)?)?;
+    Ok(())
+}
+
+const SCROLLBAR_WIDTH: f32 = 10.0;
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SelectionMode {
+    Single,
+    Multi,
+    Extended,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ListBoxBuilder {
+    pub state: ListBoxState,
+    pub token: Py<PyAny>,
+    pub values: Vec<Py<PyAny>>,
+    pub labels: Vec<String>,
+    pub selected: Vec<bool>,
+    pub mode: SelectionMode,
+    pub width: Length,
+    pub height: Length,
+    pub padding: u16,
+    pub text_size: Option<u16>,
+    pub font: Font,
+    pub style: ListBoxStyle,
+    pub scrollbar_style: Option<ScrollableStyleSheet>,
+}
+
+impl GCProtocol for ListBoxBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        for value in &self.values {
+            visit.call(value)?;
+        }
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "list_box")]
+/// list_box($module, /, token, state, items, selected=None, *, mode="single", label=None, width=None, height=None, padding=None, text_size=None, font=None, style=None, scrollbar_style=None)
+/// --
+///
+/// A scrollable list of items, of which one or many can be selected.
+///
+/// The selection modes are:
+///
+/// * "single": A click selects the clicked item.
+/// * "multi": A click toggles the selection of the clicked item.
+/// * "extended": A click selects the clicked item, control-click toggles the selection of the item, and
+///   shift-click selects all items between the item clicked last and the clicked item.
+///
+/// A clicked list box has the keyboard focus. Then the arrow keys, page up, page down, home and end
+/// move to another item, and select it in the "single" and "extended" modes.
+/// Shift extends the selection in the "extended" mode. Space selects or toggles the current item.
+/// Typing the start of a label jumps to the next item with this label.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user changes the selection, a message ``(token, new_selection)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+///     ``new_selection`` is a :class:`set` of the selected items, so the items need to be hashable.
+/// state : ListBoxState
+///     Current state of the list box. The same object must be given between calls.
+/// items : Iterable[object]
+///     The items to show.
+/// selected : Optional[Container[object]]
+///     The currently selected items, e.g. a :class:`set`. Defaults to no selection.
+/// mode : str
+///     The selection mode: "single", "multi" or "extended".
+/// label : Optional[Callable[[object], str]]
+///     Function to get the text to display for an item. Defaults to :class:`str`.
+/// width : Optional[Length]
+///     The width of the list box. Defaults to Length.FILL.
+/// height : Optional[Length]
+///     The height of the list box. Defaults to Length.FILL.
+/// padding : Optional[int]
+///     The padding around the text of an item. Defaults to 5.
+/// text_size : Optional[int]
+///     The text size of the items.
+/// font : Optional[Font]
+///     The font of the items.
+/// style : Optional[ListBoxStyle]
+///     The style of the list and its items.
+/// scrollbar_style : Optional[ScrollableStyleSheet]
+///     The style of the scrollbar.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created list box.
+///
+/// Raises
+/// ------
+/// ValueError
+///     Unknown ``mode`` value.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return list_box(
+///             'files', self.__files_state, self.__files, self.__selected_files,
+///             mode='extended', label=lambda path: path.name,
+///         )
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('files', selection):
+///                 self.__selected_files = selection
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.pick_list()`
+/// * :func:`~pyiced.scrollable()`
+fn make_list_box(
+    py: Python,
+    token: Py<PyAny>,
+    state: &WrappedListBoxState,
+    items: &PyAny,
+    selected: Option<&PyAny>,
+    mode: Option<&str>,
+    label: Option<&PyAny>,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+    padding: Option<u16>,
+    text_size: Option<u16>,
+    font: Option<&WrappedFont>,
+    style: Option<&WrappedListBoxStyle>,
+    scrollbar_style: Option<&WrappedScrollableStyleSheet>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let mode = match mode.unwrap_or("single") {
+        "single" => SelectionMode::Single,
+        "multi" => SelectionMode::Multi,
+        "extended" => SelectionMode::Extended,
+        s => {
+            return Err(PyErr::new::<PyValueError, _>(format_to_string_ignore!(
+                "Unknown mode value: {:#}",
+                s
+            )));
+        },
+    };
+    let make_label = |value: &PyAny| -> PyResult<String> {
+        match label {
+            Some(label) => Ok(label.call1((value,))?.str()?.to_string()),
+            None => Ok(value.str()?.to_string()),
+        }
+    };
+    let is_selected = |value: &PyAny| match selected {
+        Some(selected) if !selected.is_none() => selected.contains(value).unwrap_or_else(|err| {
+            err.print(py);
+            false
+        }),
+        _ => false,
+    };
+
+    let mut values = Vec::new();
+    let mut labels = Vec::new();
+    let mut selection = Vec::new();
+    for child in items.iter()? {
+        let child = match child {
+            Ok(child) => child,
+            Err(err) => {
+                err.print(py);
+                continue;
+            },
+        };
+        match make_label(child) {
+            Ok(label) => {
+                selection.push(is_selected(child));
+                values.push(child.into_py(py));
+                labels.push(label);
+            },
+            Err(err) => err.print(py),
+        }
+    }
+
+    let el = ListBoxBuilder {
+        state: state.0.clone(),
+        token,
+        values,
+        labels,
+        selected: selection,
+        mode,
+        width: width.map_or(Length::Fill, |o| o.0),
+        height: height.map_or(Length::Fill, |o| o.0),
+        padding: padding.unwrap_or(5),
+        text_size,
+        font: font.map_or(Font::Default, |o| o.0),
+        style: style.map(|o| o.0).unwrap_or_default(),
+        scrollbar_style: scrollbar_style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for ListBoxBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        list_box_with_state(&self.state, |state| {
+            Ok(ListBox {
+                state,
+                builder: self.clone(),
+            })
+        })
+    }
+}
+
+make_with_state! {
+    list_box_with_state(
+        crate::widgets::list_box::ListBox,
+        crate::widgets::list_box::ListBox<'this>,
+        crate::states::ListState,
+    );
+}
+
+pub(crate) struct ListBox<'a> {
+    state: &'a mut ListState,
+    builder: ListBoxBuilder,
+}
+
+/// The measurements of a list box, see [ListBox::geometry()].
+struct Geometry {
+    bounds: Rectangle,
+    row_height: f32,
+    offset: f32,
+    max_offset: f32,
+}
+
+impl Geometry {
+    fn has_scrollbar(&self) -> bool {
+        self.max_offset > 0.0
+    }
+
+    fn scrollbar(&self) -> Rectangle {
+        Rectangle {
+            x: self.bounds.x + self.bounds.width - SCROLLBAR_WIDTH,
+            width: SCROLLBAR_WIDTH,
+            ..self.bounds
+        }
+    }
+
+    fn scroller(&self) -> Rectangle {
+        let content_height = self.bounds.height + self.max_offset;
+        let height = (self.bounds.height * self.bounds.height / content_height)
+            .max(SCROLLBAR_WIDTH)
+            .min(self.bounds.height);
+        let y = self.bounds.y + (self.bounds.height - height) * self.offset / self.max_offset;
+        Rectangle {
+            y,
+            height,
+            ..self.scrollbar()
+        }
+    }
+
+    /// The bounds of a row in content coordinates, i.e. without the scroll offset.
+    fn row(&self, index: usize) -> Rectangle {
+        let width = match self.has_scrollbar() {
+            true => self.bounds.width - SCROLLBAR_WIDTH,
+            false => self.bounds.width,
+        };
+        Rectangle {
+            x: self.bounds.x,
+            y: self.bounds.y + index as f32 * self.row_height,
+            width,
+            height: self.row_height,
+        }
+    }
+
+    fn row_at(&self, point: Point, len: usize) -> Option<usize> {
+        if !self.bounds.contains(point)
+            || (self.has_scrollbar() && self.scrollbar().contains(point))
+        {
+            return None;
+        }
+        let index = ((point.y - self.bounds.y + self.offset) / self.row_height) as usize;
+        (index < len).then_some(index)
+    }
+
+    fn visible_rows(&self, len: usize) -> RangeInclusive<usize> {
+        let first = (self.offset / self.row_height) as usize;
+        let last = ((self.offset + self.bounds.height) / self.row_height) as usize;
+        first..=last.min(len.saturating_sub(1))
+    }
+
+    fn rows_per_page(&self) -> usize {
+        ((self.bounds.height / self.row_height) as usize).max(1)
+    }
+}
+
+impl ListBox<'_> {
+    fn text_size(&self, renderer: &Renderer) -> u16 {
+        self.builder
+            .text_size
+            .unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn geometry(&self, renderer: &Renderer, bounds: Rectangle) -> Geometry {
+        let row_height = (self.text_size(renderer) + 2 * self.builder.padding).max(1) as f32;
+        let content_height = self.builder.labels.len() as f32 * row_height;
+        let max_offset = (content_height - bounds.height).max(0.0);
+        Geometry {
+            bounds,
+            row_height,
+            offset: self.state.offset.clamp(0.0, max_offset),
+            max_offset,
+        }
+    }
+
+    fn scroll_to(&mut self, geometry: &Geometry, index: usize) {
+        let top = index as f32 * geometry.row_height;
+        let bottom = top + geometry.row_height;
+        let offset = match geometry.offset {
+            offset if top < offset => top,
+            offset if bottom > offset + geometry.bounds.height => bottom - geometry.bounds.height,
+            offset => offset,
+        };
+        self.state.offset = offset.clamp(0.0, geometry.max_offset);
+    }
+
+    /// Scrolls the list so that the top of the scroller is at `y`.
+    fn drag_to(&mut self, geometry: &Geometry, y: f32) {
+        let scroller = geometry.scroller();
+        let track = geometry.bounds.height - scroller.height;
+        if track > 0.0 {
+            let ratio = ((y - geometry.bounds.y) / track).clamp(0.0, 1.0);
+            self.state.offset = ratio * geometry.max_offset;
+        }
+    }
+
+    fn emit(&self, selection: Vec<bool>, messages: &mut Vec<Message>) {
+        if selection == self.builder.selected {
+            return;
+        }
+        Python::with_gil(|py| {
+            let values = self
+                .builder
+                .values
+                .iter()
+                .zip(selection)
+                .filter_map(|(value, is_selected)| is_selected.then_some(value))
+                .collect::<Vec<_>>();
+            match PySet::new(py, &values) {
+                Ok(set) => {
+                    let message = (&self.builder.token, set).into_py(py);
+                    messages.push(Message::Python(message));
+                },
+                Err(err) => err.print(py),
+            }
+        });
+    }
+
+    fn only(&self, index: usize) -> Vec<bool> {
+        (0..self.builder.selected.len())
+            .map(|i| i == index)
+            .collect()
+    }
+
+    fn toggled(&self, index: usize) -> Vec<bool> {
+        let mut selection = self.builder.selected.clone();
+        selection[index] = !selection[index];
+        selection
+    }
+
+    /// Selects the items between the anchor and `index`, and keeps the anchor.
+    fn range(&self, index: usize, extend: bool) -> Vec<bool> {
+        let anchor = self.state.anchor.unwrap_or(index);
+        let range = anchor.min(index)..=anchor.max(index);
+        self.builder
+            .selected
+            .iter()
+            .enumerate()
+            .map(|(i, &is_selected)| range.contains(&i) || (extend && is_selected))
+            .collect()
+    }
+
+    fn click(&mut self, index: usize, messages: &mut Vec<Message>) {
+        let keyboard::Modifiers { shift, .. } = self.state.modifiers;
+        let command = self.state.modifiers.is_command_pressed();
+        let selection = match self.builder.mode {
+            SelectionMode::Single => self.only(index),
+            SelectionMode::Multi => self.toggled(index),
+            SelectionMode::Extended if shift => self.range(index, command),
+            SelectionMode::Extended if command => self.toggled(index),
+            SelectionMode::Extended => self.only(index),
+        };
+        if self.builder.mode != SelectionMode::Extended || !shift {
+            self.state.anchor = Some(index);
+        }
+        self.state.cursor = Some(index);
+        self.emit(selection, messages);
+    }
+
+    /// Moves the cursor with the keyboard.
+    fn navigate(
+        &mut self,
+        geometry: &Geometry,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        messages: &mut Vec<Message>,
+    ) {
+        self.state.cursor = Some(index);
+        self.scroll_to(geometry, index);
+        let selection = match self.builder.mode {
+            SelectionMode::Single => self.only(index),
+            SelectionMode::Multi => return,
+            SelectionMode::Extended if modifiers.shift => {
+                self.range(index, modifiers.is_command_pressed())
+            },
+            SelectionMode::Extended if modifiers.is_command_pressed() => return,
+            SelectionMode::Extended => self.only(index),
+        };
+        if self.builder.mode != SelectionMode::Extended || !modifiers.shift {
+            self.state.anchor = Some(index);
+        }
+        self.emit(selection, messages);
+    }
+
+    fn toggle_cursor(&mut self, index: usize, messages: &mut Vec<Message>) {
+        let selection = match self.builder.mode {
+            SelectionMode::Single => self.only(index),
+            SelectionMode::Multi => self.toggled(index),
+            SelectionMode::Extended if self.state.modifiers.is_command_pressed() => {
+                self.toggled(index)
+            },
+            SelectionMode::Extended => self.only(index),
+        };
+        self.state.anchor = Some(index);
+        self.emit(selection, messages);
+    }
+
+    fn is_searching(&self) -> bool {
+        match self.state.searched_at {
+            Some(at) => !self.state.search.is_empty() && at.elapsed() < SEARCH_TIMEOUT,
+            None => false,
+        }
+    }
+
+    /// Finds the next item starting with the typed text.
+    fn search(&mut self, c: char) -> Option<usize> {
+        if !self.is_searching() {
+            self.state.search.clear();
+        }
+        self.state.search.extend(c.to_lowercase());
+        self.state.searched_at = Some(Instant::now());
+
+        // typing the same letter again cycles through the items starting with it
+        let len = self.builder.labels.len();
+        let start = match (self.state.cursor, self.state.search.chars().count()) {
+            (Some(cursor), 1) => cursor + 1,
+            (Some(cursor), _) => cursor,
+            (None, _) => 0,
+        };
+        (0..len).map(|i| (start + i) % len).find(|&i| {
+            self.builder.labels[i]
+                .to_lowercase()
+                .starts_with(&self.state.search)
+        })
+    }
+
+    fn on_key(
+        &mut self,
+        geometry: &Geometry,
+        key_code: KeyCode,
+        modifiers: keyboard::Modifiers,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let len = self.builder.labels.len();
+        if len == 0 {
+            return event::Status::Ignored;
+        }
+        let cursor = self.state.cursor.filter(|&cursor| cursor < len);
+        let page = geometry.rows_per_page();
+        let index = match (key_code, cursor) {
+            (KeyCode::Up, Some(cursor)) => cursor.saturating_sub(1),
+            (KeyCode::Down, Some(cursor)) => (cursor + 1).min(len - 1),
+            (KeyCode::PageUp, Some(cursor)) => cursor.saturating_sub(page),
+            (KeyCode::PageDown, Some(cursor)) => (cursor + page).min(len - 1),
+            (KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown, None) => 0,
+            (KeyCode::Home, _) => 0,
+            (KeyCode::End, _) => len - 1,
+            (KeyCode::Space, Some(cursor)) if !self.is_searching() => {
+                self.toggle_cursor(cursor, messages);
+                return event::Status::Captured;
+            },
+            (KeyCode::A, _)
+                if modifiers.is_command_pressed() && self.builder.mode != SelectionMode::Single =>
+            {
+                self.emit(vec![true; len], messages);
+                return event::Status::Captured;
+            },
+            _ => return event::Status::Ignored,
+        };
+        self.navigate(geometry, index, modifiers, messages);
+        event::Status::Captured
+    }
+
+    fn draw_row(&self, geometry: &Geometry, index: usize, cursor_position: Point) -> Primitive {
+        let style = &self.builder.style.0;
+        let bounds = geometry.row(index);
+        let is_hovered = geometry.row_at(cursor_position, self.builder.labels.len()) == Some(index);
+        let (background, text_color) = match (self.builder.selected[index], is_hovered) {
+            (true, _) => (Some(style.selected_background), style.selected_text_color),
+            (false, true) => (Some(style.hovered_background), style.hovered_text_color),
+            (false, false) => (None, style.text_color),
+        };
+
+        let mut primitives = Vec::with_capacity(3);
+        if let Some(background) = background {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(background),
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+        if self.state.focused && self.state.cursor == Some(index) {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: 0.0,
+                border_width: 1.0,
+                border_color: style.cursor_color,
+            });
+        }
+        let padding = self.builder.padding as f32;
+        primitives.push(Primitive::Text {
+            content: self.builder.labels[index].clone(),
+            bounds: Rectangle {
+                x: bounds.x + padding,
+                y: bounds.center_y(),
+                width: (bounds.width - 2.0 * padding).max(0.0),
+                height: bounds.height,
+            },
+            color: text_color,
+            size: geometry.row_height - 2.0 * padding,
+            font: self.builder.font,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+        });
+        Primitive::Group { primitives }
+    }
+
+    fn draw_scrollbar(&self, geometry: &Geometry, cursor_position: Point) -> Primitive {
+        let style: Box<dyn StyleSheet> = match self.builder.scrollbar_style {
+            Some(style) => Box::new(style),
+            None => Default::default(),
+        };
+        let scrollbar = geometry.scrollbar();
+        let style = match (self.state.grabbed_at, scrollbar.contains(cursor_position)) {
+            (Some(_), _) => style.dragging(),
+            (None, true) => style.hovered(),
+            (None, false) => style.active(),
+        };
+
+        let mut primitives = Vec::with_capacity(2);
+        if style.background.is_some() || style.border_width > 0.0 {
+            primitives.push(Primitive::Quad {
+                bounds: scrollbar,
+                background: style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+        }
+        primitives.push(Primitive::Quad {
+            bounds: geometry.scroller(),
+            background: Background::Color(style.scroller.color),
+            border_radius: style.scroller.border_radius,
+            border_width: style.scroller.border_width,
+            border_color: style.scroller.border_color,
+        });
+        Primitive::Group { primitives }
+    }
+}
+
+impl Widget<Message, Renderer> for ListBox<'_> {
+    fn width(&self) -> Length {
+        self.builder.width
+    }
+
+    fn height(&self) -> Length {
+        self.builder.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.builder.width).height(self.builder.height);
+        let text_size = self.text_size(renderer);
+        let row_height = (text_size + 2 * self.builder.padding) as f32;
+
+        let width = match self.builder.width {
+            Length::Shrink => {
+                let widest = self
+                    .builder
+                    .labels
+                    .iter()
+                    .map(|label| {
+                        let (width, _) = renderer.measure(
+                            label,
+                            text_size,
+                            self.builder.font,
+                            Size::new(f32::INFINITY, f32::INFINITY),
+                        );
+                        width.ceil()
+                    })
+                    .fold(0.0, f32::max);
+                widest + 2.0 * self.builder.padding as f32 + SCROLLBAR_WIDTH
+            },
+            _ => 0.0,
+        };
+        let height = self.builder.labels.len() as f32 * row_height;
+        Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let geometry = self.geometry(renderer, layout.bounds());
+        let style = &self.builder.style.0;
+
+        let rows = match self.builder.labels.is_empty() {
+            true => vec![],
+            false => geometry
+                .visible_rows(self.builder.labels.len())
+                .map(|index| self.draw_row(&geometry, index, cursor_position))
+                .collect(),
+        };
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds: geometry.bounds,
+                background: Background::Color(style.background),
+                border_radius: style.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Primitive::Clip {
+                bounds: geometry.bounds,
+                offset: Vector::new(0, geometry.offset as u32),
+                content: Box::new(Primitive::Group { primitives: rows }),
+            },
+        ];
+
+        // The rows are in a layer of their own, so the scrollbar and the border need one, too,
+        // to be drawn on top of them.
+        let mut decorations = vec![];
+        if geometry.has_scrollbar() {
+            decorations.push(self.draw_scrollbar(&geometry, cursor_position));
+        }
+        decorations.push(Primitive::Quad {
+            bounds: geometry.bounds,
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        });
+        primitives.push(Primitive::Clip {
+            bounds: geometry.bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Group {
+                primitives: decorations,
+            }),
+        });
+
+        let interaction = match self.state.grabbed_at {
+            Some(_) => mouse::Interaction::Grabbing,
+            None => mouse::Interaction::default(),
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.builder.width.hash(state);
+        self.builder.height.hash(state);
+        self.builder.padding.hash(state);
+        self.builder.text_size.hash(state);
+        self.builder.labels.len().hash(state);
+        if let Length::Shrink = self.builder.width {
+            self.builder.labels.hash(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let geometry = self.geometry(renderer, layout.bounds());
+        let len = self.builder.labels.len();
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.modifiers = modifiers;
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.state.focused = geometry.bounds.contains(cursor_position);
+                if !self.state.focused {
+                    return event::Status::Ignored;
+                }
+
+                if geometry.has_scrollbar() && geometry.scrollbar().contains(cursor_position) {
+                    let scroller = geometry.scroller();
+                    let grabbed_at = match scroller.contains(cursor_position) {
+                        true => cursor_position.y - scroller.y,
+                        false => scroller.height / 2.0,
+                    };
+                    self.state.grabbed_at = Some(grabbed_at);
+                    self.drag_to(&geometry, cursor_position.y - grabbed_at);
+                } else if let Some(index) = geometry.row_at(cursor_position, len) {
+                    self.click(index, messages);
+                }
+                return event::Status::Captured;
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.grabbed_at.is_some() =>
+            {
+                self.state.grabbed_at = None;
+                return event::Status::Captured;
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(grabbed_at) = self.state.grabbed_at {
+                    self.drag_to(&geometry, cursor_position.y - grabbed_at);
+                    return event::Status::Captured;
+                }
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if geometry.bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 60.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                self.state.offset = (geometry.offset - delta).clamp(0.0, geometry.max_offset);
+                return event::Status::Captured;
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.focused => {
+                return self.on_key(&geometry, key_code, modifiers, messages);
+            },
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.focused
+                    && len > 0
+                    && !c.is_control()
+                    && !self.state.modifiers.is_command_pressed()
+                    && (c != ' ' || self.is_searching()) =>
+            {
+                if let Some(index) = self.search(c) {
+                    self.navigate(&geometry, index, keyboard::Modifiers::default(), messages);
+                }
+                return event::Status::Captured;
+            },
+            _ => {},
+        }
+        event::Status::Ignored
+    }
+}
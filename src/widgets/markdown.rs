@@ -0,0 +1,443 @@
+use iced::image::Handle;
+use iced::{container, Background, Color, Element, Font, Length};
+use pulldown_cmark::{Event, Parser, Tag};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::styles::{ContainerStyle, MarkdownTheme, WrappedMarkdownTheme};
+use crate::widgets::rich_text::TextSpan;
+use crate::widgets::{
+    ColumnBuilder, ContainerBuilder, ImageBuilder, RichTextBuilder, RowBuilder, RuleBuilder,
+    TextBuilder, WidgetBuilder, WrappedWidgetBuilder,
+};
+use crate::wrapped::WrappedLength;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_markdown, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MarkdownBuilder {
+    pub content: Box<WidgetBuilder>,
+}
+
+impl GCProtocol for MarkdownBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        self.content.traverse(visit)
+    }
+}
+
+#[pyfunction(name = "markdown")]
+/// markdown($module, /, source, *, on_link=None, theme=None, width=None, padding=None)
+/// --
+///
+/// Render a CommonMark document.
+///
+/// Headings, paragraphs with emphasis, strong text, inline code and links, lists, block quotes,
+/// code blocks, rules and images are supported. Images are loaded from the local file system.
+///
+/// Parameters
+/// ----------
+/// source : str
+///     The markdown source to render.
+/// on_link : Optional[object]
+///     When the user clicks a link, a message ``(on_link, url)`` is sent to the app's
+///     :meth:`~pyiced.IcedApp.update()` method. Links are not clickable if the argument is None.
+/// theme : Optional[MarkdownTheme]
+///     The fonts, sizes and colors to use.
+/// width : Optional[Length]
+///     Width of the document.
+/// padding : Optional[int]
+///     Padding of the document.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created document.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     scrollable(
+///         self.__scroll_state,
+///         markdown(CHANGELOG, on_link='open-url', theme=self.__theme, padding=10),
+///     )
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.rich_text()`
+/// * `CommonMark <https://commonmark.org/>`_
+fn make_markdown(
+    py: Python,
+    source: &str,
+    on_link: Option<Py<PyAny>>,
+    theme: Option<&WrappedMarkdownTheme>,
+    width: Option<&WrappedLength>,
+    padding: Option<u16>,
+) -> WrappedWidgetBuilder {
+    let theme = theme.map_or_else(MarkdownTheme::default, |o| o.0);
+    let mut converter = Converter {
+        py,
+        theme: &theme,
+        on_link,
+        blocks: vec![Block {
+            kind: BlockKind::Root,
+            children: Vec::new(),
+        }],
+        spans: Vec::new(),
+        emphasis: 0,
+        strong: 0,
+        heading: None,
+        link: None,
+        code_block: None,
+        image: None,
+        quote_depth: 0,
+    };
+    for event in Parser::new(source) {
+        converter.event(event);
+    }
+    converter.flush();
+
+    let children = converter
+        .blocks
+        .pop()
+        .map(|block| block.children)
+        .unwrap_or_default();
+    let el = MarkdownBuilder {
+        content: Box::new(
+            ColumnBuilder {
+                children,
+                spacing: Some(theme.0.spacing),
+                padding,
+                width: width.map(|o| o.0),
+                ..Default::default()
+            }
+            .into(),
+        ),
+    };
+    el.into()
+}
+
+impl ToNative for MarkdownBuilder {
+    fn to_native(&self, py: Python) -> Element<'static, Message> {
+        self.content.to_native(py)
+    }
+}
+
+enum BlockKind {
+    Root,
+    Quote,
+    List(Option<u64>),
+    Item(String),
+}
+
+struct Block {
+    kind: BlockKind,
+    children: Vec<WidgetBuilder>,
+}
+
+struct Converter<'a> {
+    py: Python<'a>,
+    theme: &'a MarkdownTheme,
+    on_link: Option<Py<PyAny>>,
+    blocks: Vec<Block>,
+    spans: Vec<TextSpan>,
+    emphasis: usize,
+    strong: usize,
+    heading: Option<usize>,
+    link: Option<Py<PyAny>>,
+    code_block: Option<String>,
+    image: Option<String>,
+    quote_depth: usize,
+}
+
+impl Converter<'_> {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text, false),
+            Event::Code(text) => self.text(&text, true),
+            Event::SoftBreak => self.text(" ", false),
+            Event::HardBreak => self.text("\n", false),
+            Event::Rule => {
+                self.flush();
+                self.push(RuleBuilder {
+                    horizontal: self.theme.0.spacing.max(1),
+                    vertical: 0,
+                    style: None,
+                });
+            },
+            Event::TaskListMarker(checked) => {
+                self.text(if checked { "\u{2611} " } else { "\u{2610} " }, false)
+            },
+            Event::Html(_) | Event::FootnoteReference(_) => {},
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.flush(),
+            Tag::Heading(level, _, _) => {
+                self.flush();
+                self.heading = Some(level as usize);
+            },
+            Tag::BlockQuote => {
+                self.flush();
+                self.quote_depth += 1;
+                self.open(BlockKind::Quote);
+            },
+            Tag::CodeBlock(_) => {
+                self.flush();
+                self.code_block = Some(String::new());
+            },
+            Tag::List(start) => {
+                self.flush();
+                self.open(BlockKind::List(start));
+            },
+            Tag::Item => {
+                self.flush();
+                let marker = match self.blocks.last_mut().map(|block| &mut block.kind) {
+                    Some(BlockKind::List(Some(index))) => {
+                        let marker = crate::format_to_string_ignore!("{}.", index);
+                        *index += 1;
+                        marker.into_owned()
+                    },
+                    _ => "\u{2022}".to_owned(),
+                };
+                self.open(BlockKind::Item(marker));
+            },
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Link(_, url, _) => {
+                if self.on_link.is_some() {
+                    self.link = Some(url.into_string().into_py(self.py));
+                }
+            },
+            Tag::Image(_, url, _) => {
+                self.flush();
+                self.image = Some(url.into_string());
+            },
+            Tag::FootnoteDefinition(_)
+            | Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell
+            | Tag::Strikethrough => {},
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        let theme = self.theme;
+        let style = &theme.0;
+        match tag {
+            Tag::Paragraph => self.flush(),
+            Tag::Heading(..) => {
+                self.flush();
+                self.heading = None;
+            },
+            Tag::BlockQuote => {
+                self.flush();
+                self.quote_depth -= 1;
+                let children = self.close();
+                self.push(RowBuilder {
+                    children: vec![
+                        RuleBuilder {
+                            horizontal: 0,
+                            vertical: style.spacing.max(1),
+                            style: None,
+                        }
+                        .into(),
+                        ColumnBuilder {
+                            children,
+                            spacing: Some(style.spacing),
+                            ..Default::default()
+                        }
+                        .into(),
+                    ],
+                    spacing: Some(style.spacing),
+                    ..Default::default()
+                });
+            },
+            Tag::CodeBlock(_) => {
+                let mut code = self.code_block.take().unwrap_or_default();
+                if code.ends_with('\n') {
+                    code.pop();
+                }
+                let code = RichTextBuilder {
+                    spans: vec![TextSpan {
+                        text: code,
+                        ..Default::default()
+                    }],
+                    size: Some(style.code_size),
+                    color: style.code_color,
+                    font: style.code_font,
+                    ..Default::default()
+                };
+                self.push(ContainerBuilder {
+                    content: Box::new(code.into()),
+                    padding: Some(style.spacing),
+                    width: Some(Length::Fill),
+                    style: Some(ContainerStyle(container::Style {
+                        background: style.code_background.map(Background::Color),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                });
+            },
+            Tag::List(_) => {
+                self.flush();
+                let children = self.close();
+                self.push(ColumnBuilder {
+                    children,
+                    spacing: Some(style.spacing / 2),
+                    ..Default::default()
+                });
+            },
+            Tag::Item => {
+                self.flush();
+                let marker = match self.blocks.last() {
+                    Some(Block {
+                        kind: BlockKind::Item(marker),
+                        ..
+                    }) => marker.clone(),
+                    _ => String::new(),
+                };
+                let children = self.close();
+                self.push(RowBuilder {
+                    children: vec![
+                        TextBuilder {
+                            label: marker,
+                            size: Some(style.text_size),
+                            color: self.color(),
+                            font: style.font,
+                            ..Default::default()
+                        }
+                        .into(),
+                        ColumnBuilder {
+                            children,
+                            spacing: Some(style.spacing / 2),
+                            ..Default::default()
+                        }
+                        .into(),
+                    ],
+                    spacing: Some(style.spacing / 2),
+                    ..Default::default()
+                });
+            },
+            Tag::Emphasis => self.emphasis = self.emphasis.saturating_sub(1),
+            Tag::Strong => self.strong = self.strong.saturating_sub(1),
+            Tag::Link(..) => self.link = None,
+            Tag::Image(..) => {
+                if let Some(path) = self.image.take() {
+                    self.push(ImageBuilder {
+                        handle: Handle::from_path(path),
+                        width: None,
+                        height: None,
+                        options: None,
+                    });
+                }
+            },
+            Tag::FootnoteDefinition(_)
+            | Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell
+            | Tag::Strikethrough => {},
+        }
+    }
+
+    fn text(&mut self, text: &str, code: bool) {
+        if let Some(code_block) = &mut self.code_block {
+            code_block.push_str(text);
+            return;
+        }
+        if self.image.is_some() {
+            // the alternative text of the image
+            return;
+        }
+
+        let theme = self.theme;
+        let style = &theme.0;
+        let (color, font) = match code {
+            true => (style.code_color.or_else(|| self.color()), style.code_font),
+            false => (self.color(), self.font()),
+        };
+        self.spans.push(TextSpan {
+            text: text.to_owned(),
+            color,
+            font,
+            size: self.heading.map(|level| self.theme.heading_size(level)),
+            underline: self.link.is_some(),
+            link: self.link.clone(),
+        });
+    }
+
+    fn color(&self) -> Option<Color> {
+        let theme = self.theme;
+        let style = &theme.0;
+        if self.link.is_some() {
+            style.link_color
+        } else if self.heading.is_some() && style.heading_color.is_some() {
+            style.heading_color
+        } else if self.quote_depth > 0 && style.quote_color.is_some() {
+            style.quote_color
+        } else {
+            style.text_color
+        }
+    }
+
+    fn font(&self) -> Option<Font> {
+        let theme = self.theme;
+        let style = &theme.0;
+        if self.heading.is_some() && style.heading_font.is_some() {
+            style.heading_font
+        } else if self.strong > 0 && style.strong_font.is_some() {
+            style.strong_font
+        } else if self.emphasis > 0 && style.emphasis_font.is_some() {
+            style.emphasis_font
+        } else {
+            style.font
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.spans.is_empty() {
+            return;
+        }
+        let theme = self.theme;
+        let style = &theme.0;
+        let el = RichTextBuilder {
+            token: self.on_link.clone(),
+            spans: std::mem::take(&mut self.spans),
+            size: Some(style.text_size),
+            color: style.text_color,
+            font: style.font,
+            ..Default::default()
+        };
+        self.push(el);
+    }
+
+    fn push(&mut self, el: impl Into<WidgetBuilder>) {
+        if let Some(block) = self.blocks.last_mut() {
+            block.children.push(el.into());
+        }
+    }
+
+    fn open(&mut self, kind: BlockKind) {
+        self.blocks.push(Block {
+            kind,
+            children: Vec::new(),
+        });
+    }
+
+    fn close(&mut self) -> Vec<WidgetBuilder> {
+        match self.blocks.len() {
+            0 | 1 => Vec::new(),
+            _ => self.blocks.pop().map(|b| b.children).unwrap_or_default(),
+        }
+    }
+}
@@ -64,27 +64,47 @@ macro_rules! init_mod {
 
 init_mod!(
     NoElement(no_element -> NoElementBuilder),
+    AnimatedImage(animated_image -> AnimatedImageBuilder),
     Button(button -> ButtonBuilder),
     // TODO: Canvas
     Checkbox(checkbox -> CheckboxBuilder),
+    ColorPicker(color_picker -> ColorPickerBuilder),
     Column(column -> ColumnBuilder),
+    ComboBox(combo_box -> ComboBoxBuilder),
     Container(container -> ContainerBuilder),
+    DatePicker(date_picker -> DatePickerBuilder),
+    External(external -> ExternalBuilder),
+    Grid(grid -> GridBuilder),
     Image(image -> ImageBuilder),
+    ImageViewer(image_viewer -> ImageViewerBuilder),
+    ListBox(list_box -> ListBoxBuilder),
+    Markdown(markdown -> MarkdownBuilder),
+    NumberInput(number_input -> NumberInputBuilder),
     // TODO: PaneGrid
     PickList(pick_list -> PickListBuilder),
     ProgressBar(progress_bar -> ProgressBarBuilder),
     Radio(radio -> RadioBuilder),
+    RangeSlider(range_slider -> RangeSliderBuilder),
+    RichText(rich_text -> RichTextBuilder),
     Row(row -> RowBuilder),
     Rule(rule -> RuleBuilder),
     Scrollable(scrollable -> ScrollableBuilder),
+    SelectableText(selectable_text -> SelectableTextBuilder),
     Slider(slider -> SliderBuilder),
     Space(space -> SpaceBuilder),
+    Stack(stack -> StackBuilder),
     Svg(svg -> SvgBuilder),
     Text(text -> TextBuilder),
     TextInput(text_input -> TextInputBuilder),
+    TimePicker(time_picker -> TimePickerBuilder),
+    Toggler(toggler -> TogglerBuilder),
     Tooltip(tooltip -> TooltipBuilder),
+    Wrap(wrap -> WrapBuilder),
 );
 
+pub(crate) use self::animated_image::subscription as animated_image_subscription;
+pub(crate) use self::image::subscription as picture_subscription;
+
 /// A displayable widget that can be used in :meth:`~pyiced.IcedApp.view()`.
 #[pyclass(name = "Element", module = "pyiced")]
 #[derive(Debug, Clone)]
@@ -0,0 +1,616 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use iced::text_input::StyleSheet;
+use iced::{
+    Element, Font, HorizontalAlignment, Length, Point, Rectangle, Size, TextInput,
+    VerticalAlignment,
+};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{event, keyboard, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyLong;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{EitherPy, GCProtocol, Message, ToNative};
+use crate::states::{NumberInputState, NumberState, WrappedNumberInputState};
+use crate::styles::{TextInputStyleSheet, WrappedTextInputStyleSheet};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedLength};
+use crate::{format_to_string_ignore, make_with_state};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_number_input, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NumberInputBuilder {
+    pub state: NumberInputState,
+    pub token: Py<PyAny>,
+    pub value: Number,
+    pub min: Number,
+    pub max: Number,
+    pub step: Number,
+    pub decimals: Option<usize>,
+    pub placeholder: String,
+    pub font: Option<Font>,
+    pub width: Length,
+    pub padding: u16,
+    pub size: Option<u16>,
+    pub style: Option<TextInputStyleSheet>,
+}
+
+/// A value of a number input. All values of an input have the same variant.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub(crate) enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl GCProtocol for NumberInputBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "number_input")]
+/// number_input($module, /, token, state, value, *, min=None, max=None, step=1, decimals=None, placeholder="", font=None, width=None, padding=None, size=None, style=None)
+/// --
+///
+/// A field to enter a number, with buttons to increment and decrement it.
+///
+/// The value can also be stepped with the mouse wheel, and with the up and down keys while typing.
+///
+/// If ``value`` is an :class:`int`, then only integers can be entered. Otherwise the value is a :class:`float`.
+///
+/// Only valid values are sent to the app. While the user is typing, a message is sent whenever the text is a number
+/// inside the limits. When the user hits enter or clicks somewhere else, the text is parsed and clamped into the limits,
+/// or reset to ``value`` if it is not a number.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the value changes, a message ``(token, new_value)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// state : NumberInputState
+///     Current state of the input element. The same object must be given between calls.
+/// value : Union[int, float]
+///     Current value of the input element.
+/// min : Optional[Union[int, float]]
+///     The smallest allowed value. Unbounded by default.
+/// max : Optional[Union[int, float]]
+///     The largest allowed value. Unbounded by default.
+/// step : Union[int, float]
+///     The amount to add or subtract with the buttons, the mouse wheel or the keyboard.
+/// decimals : Optional[int]
+///     The number of decimal places to round a float value to. Shows the shortest representation by default.
+/// placeholder : str
+///     Placeholder text if the field is empty.
+/// font : Optional[Font]
+///     The font of the text.
+/// width : Optional[Length]
+///     The width of the element including the buttons. Defaults to Length.FILL.
+/// padding : Optional[int]
+///     The padding of the text.
+/// size : Optional[int]
+///     The text size of the input element.
+/// style : Optional[TextInputStyleSheet]
+///     Style of the text part. The buttons use its "active" and "hovered" styles.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created number input element.
+///
+/// Raises
+/// ------
+/// ValueError
+///     The limits are empty or an argument is not finite.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return number_input('quantity', self.__quantity_state, self.__quantity, min=1, max=99)
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('quantity', value):
+///                 self.__quantity = value
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.text_input()`
+/// * :func:`~pyiced.slider()`
+fn make_number_input(
+    token: Py<PyAny>,
+    state: &WrappedNumberInputState,
+    value: &PyAny,
+    min: Option<EitherPy<i64, f64>>,
+    max: Option<EitherPy<i64, f64>>,
+    step: Option<EitherPy<i64, f64>>,
+    decimals: Option<usize>,
+    placeholder: Option<String>,
+    font: Option<&WrappedFont>,
+    width: Option<&WrappedLength>,
+    padding: Option<u16>,
+    size: Option<u16>,
+    style: Option<&WrappedTextInputStyleSheet>,
+) -> PyResult<WrappedWidgetBuilder> {
+    if let Some(EitherPy::Right(step)) = step {
+        if !step.is_finite() {
+            return Err(not_finite());
+        }
+    }
+    let (value, min, max, step) = match value.is_instance_of::<PyLong>()? {
+        true => (
+            Number::Int(value.extract()?),
+            Number::Int(to_int(min, f64::ceil)?.unwrap_or(i64::MIN)),
+            Number::Int(to_int(max, f64::floor)?.unwrap_or(i64::MAX)),
+            Number::Int(to_int(step, f64::round)?.unwrap_or(1)),
+        ),
+        false => {
+            let value: f64 = value.extract()?;
+            if !value.is_finite() {
+                return Err(not_finite());
+            }
+            (
+                Number::Float(value),
+                Number::Float(to_float(min)?.unwrap_or(f64::NEG_INFINITY)),
+                Number::Float(to_float(max)?.unwrap_or(f64::INFINITY)),
+                Number::Float(to_float(step)?.unwrap_or(1.0)),
+            )
+        },
+    };
+    if min > max {
+        return Err(PyErr::new::<PyValueError, _>(
+            "Expected 'min' to be less than or equal to 'max'",
+        ));
+    }
+
+    let el = NumberInputBuilder {
+        state: state.0.clone(),
+        token,
+        value,
+        min,
+        max,
+        step,
+        decimals,
+        placeholder: placeholder.unwrap_or_default(),
+        font: font.map(|o| o.0),
+        width: width.map_or(Length::Fill, |o| o.0),
+        padding: padding.unwrap_or(0),
+        size,
+        style: style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+fn not_finite() -> PyErr {
+    PyErr::new::<PyValueError, _>("Expected 'value', 'step', 'min' and 'max' to be finite")
+}
+
+fn to_int(value: Option<EitherPy<i64, f64>>, round: fn(f64) -> f64) -> PyResult<Option<i64>> {
+    match value {
+        None => Ok(None),
+        Some(EitherPy::Left(value)) => Ok(Some(value)),
+        Some(EitherPy::Right(value)) if value.is_nan() => Err(not_finite()),
+        // saturates at the limits of i64
+        Some(EitherPy::Right(value)) => Ok(Some(round(value) as i64)),
+    }
+}
+
+fn to_float(value: Option<EitherPy<i64, f64>>) -> PyResult<Option<f64>> {
+    match value {
+        None => Ok(None),
+        Some(EitherPy::Left(value)) => Ok(Some(value as f64)),
+        Some(EitherPy::Right(value)) if value.is_nan() => Err(not_finite()),
+        Some(EitherPy::Right(value)) => Ok(Some(value)),
+    }
+}
+
+impl NumberInputBuilder {
+    fn parse(&self, text: &str) -> Option<Number> {
+        let text = text.trim();
+        match self.value {
+            Number::Int(_) => text.parse().ok().map(Number::Int),
+            Number::Float(_) => text
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .map(Number::Float),
+        }
+    }
+
+    fn round(&self, value: Number) -> Number {
+        match (value, self.decimals) {
+            (Number::Float(value), Some(decimals)) => {
+                let factor = 10f64.powi(decimals as i32);
+                Number::Float((value * factor).round() / factor)
+            },
+            (value, _) => value,
+        }
+    }
+
+    fn clamp(&self, value: Number) -> Number {
+        match (value, self.min, self.max) {
+            (Number::Int(value), Number::Int(min), Number::Int(max)) => {
+                Number::Int(value.clamp(min, max))
+            },
+            (Number::Float(value), Number::Float(min), Number::Float(max)) => {
+                Number::Float(value.clamp(min, max))
+            },
+            (value, _, _) => value,
+        }
+    }
+
+    /// Adds `steps` times the step size, saturating at the limits of the type.
+    fn add_steps(&self, value: Number, steps: i64) -> Number {
+        match (value, self.step) {
+            (Number::Int(value), Number::Int(step)) => {
+                Number::Int(value.saturating_add(step.saturating_mul(steps)))
+            },
+            (Number::Float(value), Number::Float(step)) => {
+                Number::Float(value + steps as f64 * step)
+            },
+            (value, _) => value,
+        }
+    }
+
+    fn format(&self, value: Number) -> String {
+        let text = match (value, self.decimals) {
+            (Number::Int(value), _) => format_to_string_ignore!("{}", value),
+            (Number::Float(value), Some(decimals)) => {
+                format_to_string_ignore!("{:.*}", decimals, value)
+            },
+            (Number::Float(value), None) => format_to_string_ignore!("{}", value),
+        };
+        text.into_owned()
+    }
+}
+
+impl ToNative for NumberInputBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        number_input_with_state(&self.state, |state| {
+            let NumberState {
+                input,
+                editing,
+                focused,
+            } = state;
+            let text = match editing {
+                Some(text) => text.clone(),
+                None => self.format(self.value),
+            };
+
+            // The text is checked before it is sent to the app, so keep it for on_event().
+            let changed = Rc::new(RefCell::new(None));
+            let on_change = {
+                let changed = Rc::clone(&changed);
+                move |s| {
+                    *changed.borrow_mut() = Some(s);
+                    Message::None
+                }
+            };
+            let el = TextInput::new(input, &self.placeholder, &text, on_change)
+                .width(Length::Fill)
+                .padding(self.padding);
+            let el = match self.font {
+                Some(font) => el.font(font),
+                None => el,
+            };
+            let el = match self.size {
+                Some(size) => el.size(size),
+                None => el,
+            };
+            let el = match self.style {
+                Some(style) => el.style(style),
+                None => el,
+            };
+
+            Ok(NumberInput {
+                input: el,
+                editing,
+                focused,
+                changed,
+                style: match self.style {
+                    Some(style) => Box::new(style),
+                    None => Default::default(),
+                },
+                builder: self.clone(),
+            })
+        })
+    }
+}
+
+make_with_state! {
+    number_input_with_state(
+        crate::widgets::number_input::NumberInput,
+        crate::widgets::number_input::NumberInput<'this>,
+        crate::states::NumberState,
+    );
+}
+
+pub(crate) struct NumberInput<'a> {
+    input: TextInput<'a, Message>,
+    editing: &'a mut Option<String>,
+    focused: &'a mut bool,
+    changed: Rc<RefCell<Option<String>>>,
+    style: Box<dyn StyleSheet>,
+    builder: NumberInputBuilder,
+}
+
+impl NumberInput<'_> {
+    fn emit(&self, value: Number, messages: &mut Vec<Message>) {
+        if value == self.builder.value {
+            return;
+        }
+        let token = &self.builder.token;
+        let message = Python::with_gil(|py| match value {
+            Number::Int(value) => (token, value).into_py(py),
+            Number::Float(value) => (token, value).into_py(py),
+        });
+        messages.push(Message::Python(message));
+    }
+
+    /// The user typed something. Only a number inside the limits is sent to the app.
+    fn edit(&mut self, text: String, messages: &mut Vec<Message>) {
+        if let Some(value) = self.builder.parse(&text) {
+            if (self.builder.min..=self.builder.max).contains(&value) {
+                self.emit(self.builder.round(value), messages);
+            }
+        }
+        *self.editing = Some(text);
+    }
+
+    /// The user finished typing. The text is clamped, or discarded if it is not a number.
+    fn commit(&mut self, messages: &mut Vec<Message>) {
+        if let Some(text) = self.editing.take() {
+            if let Some(value) = self.builder.parse(&text) {
+                let value = self.builder.round(value);
+                self.emit(self.builder.clamp(value), messages);
+            }
+        }
+    }
+
+    fn step(&mut self, steps: i64, messages: &mut Vec<Message>) {
+        let value = match self.editing.take() {
+            Some(text) => self.builder.parse(&text).unwrap_or(self.builder.value),
+            None => self.builder.value,
+        };
+        let value = self.builder.round(self.builder.add_steps(value, steps));
+        self.emit(self.builder.clamp(value), messages);
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> u16 {
+        self.builder.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn draw_button(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+        label: &str,
+        enabled: bool,
+    ) -> Primitive {
+        let style = match enabled && bounds.contains(cursor_position) {
+            true => self.style.hovered(),
+            false => self.style.active(),
+        };
+        let color = match enabled {
+            true => self.style.value_color(),
+            false => self.style.placeholder_color(),
+        };
+        Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds,
+                    background: style.background,
+                    border_radius: style.border_radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                },
+                Primitive::Text {
+                    content: label.to_owned(),
+                    bounds: Rectangle {
+                        x: bounds.center_x(),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    color,
+                    size: self.text_size(renderer) as f32,
+                    font: self.builder.font.unwrap_or_default(),
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                },
+            ],
+        }
+    }
+}
+
+impl Widget<Message, Renderer> for NumberInput<'_> {
+    fn width(&self) -> Length {
+        self.builder.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.builder.width).height(Length::Shrink);
+        let side = (self.text_size(renderer) + 2 * self.builder.padding) as f32;
+
+        let max = limits.max();
+        let input_limits = Limits::new(
+            Size::ZERO,
+            Size::new((max.width - 2.0 * side).max(0.0), max.height),
+        );
+        let input = Widget::<Message, Renderer>::layout(&self.input, renderer, &input_limits);
+        let width = input.size().width;
+
+        let mut decrement = Node::new(Size::new(side, side));
+        decrement.move_to(Point::new(width, 0.0));
+        let mut increment = Node::new(Size::new(side, side));
+        increment.move_to(Point::new(width + side, 0.0));
+        Node::with_children(Size::new(width + 2.0 * side, side), vec![
+            input, decrement, increment,
+        ])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let mut children = layout.children();
+        let (input, decrement, increment) =
+            match (children.next(), children.next(), children.next()) {
+                (Some(input), Some(decrement), Some(increment)) => (input, decrement, increment),
+                _ => return (Primitive::None, mouse::Interaction::default()),
+            };
+
+        let (input, interaction) = Widget::<Message, Renderer>::draw(
+            &self.input,
+            renderer,
+            defaults,
+            input,
+            cursor_position,
+            viewport,
+        );
+        let (decrement, increment) = (decrement.bounds(), increment.bounds());
+        let can_decrement = self.builder.value > self.builder.min;
+        let can_increment = self.builder.value < self.builder.max;
+        let primitive = Primitive::Group {
+            primitives: vec![
+                input,
+                self.draw_button(renderer, decrement, cursor_position, "-", can_decrement),
+                self.draw_button(renderer, increment, cursor_position, "+", can_increment),
+            ],
+        };
+
+        let interaction = match (
+            decrement.contains(cursor_position),
+            increment.contains(cursor_position),
+        ) {
+            (true, _) if can_decrement => mouse::Interaction::Pointer,
+            (_, true) if can_increment => mouse::Interaction::Pointer,
+            (false, false) => interaction,
+            _ => mouse::Interaction::default(),
+        };
+        (primitive, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.builder.width.hash(state);
+        self.builder.padding.hash(state);
+        self.builder.size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let (input, decrement, increment) =
+            match (children.next(), children.next(), children.next()) {
+                (Some(input), Some(decrement), Some(increment)) => (input, decrement, increment),
+                _ => return event::Status::Ignored,
+            };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if decrement.bounds().contains(cursor_position) {
+                    self.step(-1, messages);
+                    return event::Status::Captured;
+                } else if increment.bounds().contains(cursor_position) {
+                    self.step(1, messages);
+                    return event::Status::Captured;
+                }
+
+                // the text input loses its focus if the click was outside of it
+                let is_clicked = input.bounds().contains(cursor_position);
+                if *self.focused && !is_clicked {
+                    self.commit(messages);
+                }
+                *self.focused = is_clicked;
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let steps = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                if steps == 0.0 {
+                    return event::Status::Ignored;
+                }
+                self.step(steps.signum() as i64, messages);
+                return event::Status::Captured;
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if *self.focused => {
+                match key_code {
+                    KeyCode::Enter | KeyCode::NumpadEnter => self.commit(messages),
+                    KeyCode::Up => self.step(1, messages),
+                    KeyCode::Down => self.step(-1, messages),
+                    _ => {
+                        return self.delegate(
+                            event,
+                            input,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            messages,
+                        );
+                    },
+                }
+                return event::Status::Captured;
+            },
+            _ => {},
+        }
+
+        self.delegate(event, input, cursor_position, renderer, clipboard, messages)
+    }
+}
+
+impl NumberInput<'_> {
+    fn delegate(
+        &mut self,
+        event: Event,
+        input: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        // The text input sends Message::None, which is dropped by the app, but still makes iced
+        // rebuild the view, so that the edited text is shown even if it is not a valid number.
+        let status =
+            self.input
+                .on_event(event, input, cursor_position, renderer, clipboard, messages);
+        let changed = self.changed.borrow_mut().take();
+        if let Some(text) = changed {
+            self.edit(text, messages);
+        }
+        status
+    }
+}
@@ -6,7 +6,7 @@ use pyo3::wrap_pyfunction;
 
 use crate::assign;
 use crate::common::{GCProtocol, Message, ToNative};
-use crate::states::{pick_list_with_state, PickListState, WrappedPickListState};
+use crate::states::{pick_list_with_state, PickListOption, PickListState, WrappedPickListState};
 use crate::styles::{PickListStyleSheet, WrappedPickListStyleSheet};
 use crate::widgets::WrappedWidgetBuilder;
 use crate::wrapped::WrappedFont;
@@ -19,8 +19,9 @@ pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
 #[derive(Debug, Clone)]
 pub(crate) struct PickListBuilder {
     pub state: PickListState,
-    pub options: Vec<String>,
-    pub selected: Option<String>,
+    pub options: Vec<PickListOption>,
+    pub values: Vec<Py<PyAny>>,
+    pub selected: Option<PickListOption>,
     pub token: Py<PyAny>,
     pub text_size: Option<u16>,
     pub font: Option<Font>,
@@ -29,27 +30,36 @@ pub(crate) struct PickListBuilder {
 
 impl GCProtocol for PickListBuilder {
     fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        for value in &self.values {
+            visit.call(value)?;
+        }
         visit.call(&self.token)?;
         Ok(())
     }
 }
 
 #[pyfunction(name = "pick_list")]
-/// pick_list($module, /, token, state, selected, options, *, text_size=None, font=None, style=None)
+/// pick_list($module, /, token, state, selected, options, *, label=None, text_size=None, font=None, style=None)
 /// --
 ///
 /// A widget for selecting a single value from a list of options.
 ///
+/// The options can be any objects, e.g. members of an :class:`~enum.Enum` or dataclass instances.
+/// The selected value is found in the options by identity or by equality (``==``).
+///
 /// Parameters
 /// ----------
 /// token : object
 ///     When the user select a value, a message ``(token, new_value)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+///     ``new_value`` is the very object from ``options``.
 /// state : PickListState
 ///     Current state of the pick list. The same object must be given between calls.
-/// selected : Optional[str]
+/// selected : Optional[object]
 ///     The currently selected value.
-/// options : Iterable[Optional[str]]
-///     Values to select from.
+/// options : Iterable[Optional[object]]
+///     Values to select from. None values are ignored.
+/// label : Optional[Callable[[object], str]]
+///     Function to get the text to display for an option. Defaults to :class:`str`.
 /// text_size : Optional[int]
 ///     The text size of the pick list.
 /// font : Optional[Font]
@@ -78,32 +88,67 @@ fn make_pick_list(
     py: Python,
     token: Py<PyAny>,
     state: &WrappedPickListState,
-    selected: Option<String>,
+    selected: Option<&PyAny>,
     options: &PyAny,
+    label: Option<&PyAny>,
     text_size: Option<u16>,
     font: Option<&WrappedFont>,
     style: Option<&WrappedPickListStyleSheet>,
 ) -> PyResult<WrappedWidgetBuilder> {
-    let options = options
-        .iter()?
-        .filter_map(|child| match child {
-            Ok(child) if !child.is_none() => match child.str() {
-                Ok(s) => Some(s.to_string()),
-                Err(err) => {
-                    err.print(py);
-                    None
-                },
-            },
-            Ok(_) => None,
+    let make_label = |value: &PyAny| -> PyResult<String> {
+        match label {
+            Some(label) => Ok(label.call1((value,))?.str()?.to_string()),
+            None => Ok(value.str()?.to_string()),
+        }
+    };
+    let is_selected = |value: &PyAny| match selected {
+        Some(selected) if value.is(selected) => true,
+        Some(selected) => value.eq(selected).unwrap_or_else(|err| {
+            err.print(py);
+            false
+        }),
+        None => false,
+    };
+
+    let mut values = Vec::new();
+    let mut entries = Vec::new();
+    let mut selected_index = None;
+    for child in options.iter()? {
+        let child = match child {
+            Ok(child) if !child.is_none() => child,
+            Ok(_) => continue,
             Err(err) => {
                 err.print(py);
-                None
+                continue;
             },
-        })
-        .collect();
+        };
+        match make_label(child) {
+            Ok(label) => {
+                if selected_index.is_none() && is_selected(child) {
+                    selected_index = Some(values.len());
+                }
+                entries.push(PickListOption {
+                    index: Some(values.len()),
+                    label,
+                });
+                values.push(child.into_py(py));
+            },
+            Err(err) => err.print(py),
+        }
+    }
+    let selected = match (selected_index, selected) {
+        (Some(index), _) => Some(entries[index].clone()),
+        (None, Some(selected)) if !selected.is_none() => Some(PickListOption {
+            index: None,
+            label: make_label(selected)?,
+        }),
+        (None, _) => None,
+    };
+
     let el = PickListBuilder {
         state: state.0.clone(),
-        options,
+        options: entries,
+        values,
         selected,
         token,
         text_size,
@@ -116,8 +161,12 @@ fn make_pick_list(
 impl ToNative for PickListBuilder {
     fn to_native(&self, _py: Python) -> Element<'static, Message> {
         let token = self.token.clone();
-        let on_selected = move |value| {
-            Python::with_gil(|py| Message::Python((token.clone(), &value).into_py(py)))
+        let values = self.values.clone();
+        let on_selected = move |option: PickListOption| match option.index {
+            Some(index) => Python::with_gil(|py| {
+                Message::Python((token.clone(), &values[index]).into_py(py))
+            }),
+            None => Message::None,
         };
         pick_list_with_state(&self.state, move |state| {
             let options = Cow::Owned(self.options.clone());
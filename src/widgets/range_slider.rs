@@ -0,0 +1,178 @@
+use iced::{Element, Length};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{some_err, validate_f32, EitherPy, GCProtocol, Message, ToNative};
+use crate::states::{SliderState, WrappedSliderState};
+use crate::styles::{SliderStyleSheet, WrappedSliderStyleSheet};
+use crate::widgets::slider::{slider_with_state, Orientation, Slider};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::WrappedLength;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_range_slider, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RangeSliderBuilder {
+    pub token: Py<PyAny>,
+    pub state: SliderState,
+    pub start: f32,
+    pub end: f32,
+    pub low: f32,
+    pub high: f32,
+    pub width: Length,
+    pub height: Length,
+    pub step: Option<f32>,
+    pub orientation: Orientation,
+    pub release_only: bool,
+    pub on_drag_start: Option<Py<PyAny>>,
+    pub on_release: Option<Py<PyAny>>,
+    pub style: Option<SliderStyleSheet>,
+}
+
+impl GCProtocol for RangeSliderBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.token)?;
+        if let Some(on_drag_start) = &self.on_drag_start {
+            visit.call(on_drag_start)?;
+        }
+        if let Some(on_release) = &self.on_release {
+            visit.call(on_release)?;
+        }
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "range_slider")]
+/// range_slider($module, /, token, state, start, end, value, step=1.0, *, width=None, height=None, orientation="horizontal", release_only=False, on_drag_start=None, on_release=None, style=None)
+/// --
+///
+/// A bar and two handles that select a range of values.
+///
+/// A click on the bar moves the nearest handle. If a handle is dragged over the other one, then they change their roles.
+/// The last moved handle can be moved with the keyboard like in a :func:`~pyiced.slider()`.
+/// The mouse wheel moves the last moved handle, too, if the mouse cursor is over the range slider.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user selects a range, a message ``(token, (new_low, new_high))`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// state : SliderState
+///     Current state of the slider. The same object must be given between calls.
+/// start : float
+///     Smallest value inside the range.
+/// end : float
+///     Biggest value inside the range.
+/// value : Tuple[float, float]
+///     Currently selected range ``(low, high)``.
+/// step : float
+///     Step size of the slider.
+/// width : Optional[Length]
+///     Width of the slider. Defaults to Length.FILL for an horizontal slider, and 22 units for a vertical slider.
+/// height : Union[int, Length, None]
+///     Height of the slider. Defaults to 22 units for an horizontal slider, and Length.FILL for a vertical slider.
+/// orientation : Literal['horizontal', 'vertical']
+///     The direction of the bar. The start of a vertical slider is at the bottom.
+/// release_only : bool
+///     If True, then ``(token, (new_low, new_high))`` is only sent once when the user releases the slider,
+///     and not every time a handle is moved.
+/// on_drag_start : Optional[object]
+///     When the user starts to drag the slider, a message ``(on_drag_start, (low, high))`` is sent with the range before the drag.
+/// on_release : Optional[object]
+///     When the user releases the slider, a message ``(on_release, (low, high))`` is sent with the final range.
+/// style : SliderStyleSheet
+///     The normal style of the slider. The selected range is drawn in the border color of the handle.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created range slider.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return range_slider('price', self.__price_state, 0, 500, self.__price, 10)
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('price', (low, high)):
+///                 self.__price = low, high
+///
+/// See also
+/// --------
+/// :func:`~pyiced.slider()`
+fn make_range_slider(
+    token: Py<PyAny>,
+    state: &WrappedSliderState,
+    start: f32,
+    end: f32,
+    value: (f32, f32),
+    step: Option<f32>,
+    width: Option<&WrappedLength>,
+    height: Option<EitherPy<u16, WrappedLength>>,
+    orientation: Option<&str>,
+    release_only: Option<bool>,
+    on_drag_start: Option<Py<PyAny>>,
+    on_release: Option<Py<PyAny>>,
+    style: Option<&WrappedSliderStyleSheet>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let start = validate_f32(start)?;
+    let end = validate_f32(end)?;
+    let low = validate_f32(value.0)?;
+    let high = validate_f32(value.1)?;
+
+    if start > low || low > high || high > end {
+        return Err(PyErr::new::<PyValueError, _>(
+            "The following comparison must be true: start <= low <= high <= end",
+        ));
+    }
+
+    let orientation = Orientation::parse(orientation)?;
+    let (width, height) = orientation.size(width, height);
+    let el = RangeSliderBuilder {
+        token,
+        state: state.0.clone(),
+        start,
+        end,
+        low,
+        high,
+        width,
+        height,
+        step: some_err(step.map(validate_f32))?,
+        orientation,
+        release_only: release_only.unwrap_or(false),
+        on_drag_start,
+        on_release,
+        style: style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for RangeSliderBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        slider_with_state(&self.state, |state| {
+            state.fit(2);
+            Ok(Slider {
+                state,
+                token: self.token.clone(),
+                start: self.start,
+                end: self.end,
+                values: vec![self.low, self.high],
+                step: self.step.unwrap_or(1.0),
+                width: self.width,
+                height: self.height,
+                orientation: self.orientation,
+                release_only: self.release_only,
+                on_drag_start: self.on_drag_start.clone(),
+                on_release: self.on_release.clone(),
+                legacy_release: false,
+                style: self.style,
+            })
+        })
+    }
+}
@@ -0,0 +1,512 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::{
+    Background, Color, Element, Font, HorizontalAlignment, Length, Point, Rectangle, Size,
+    VerticalAlignment,
+};
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{event, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{debug_str, EitherPy, GCProtocol, Message, ToNative};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedColor, WrappedFont, WrappedHorizontalAlignment, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedTextSpan>()?;
+    m.add_function(wrap_pyfunction!(make_span, m)?)?;
+    m.add_function(wrap_pyfunction!(make_rich_text, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RichTextBuilder {
+    pub token: Option<Py<PyAny>>,
+    pub spans: Vec<TextSpan>,
+    pub size: Option<u16>,
+    pub color: Option<Color>,
+    pub font: Option<Font>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub horizontal_alignment: Option<HorizontalAlignment>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TextSpan {
+    pub text: String,
+    pub color: Option<Color>,
+    pub font: Option<Font>,
+    pub size: Option<u16>,
+    pub underline: bool,
+    pub link: Option<Py<PyAny>>,
+}
+
+impl GCProtocol for TextSpan {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        if let Some(link) = &self.link {
+            visit.call(link)?;
+        }
+        Ok(())
+    }
+}
+
+impl GCProtocol for RichTextBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        if let Some(token) = &self.token {
+            visit.call(token)?;
+        }
+        for span in self.spans.iter() {
+            span.traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+/// A styled run of text inside a :func:`~pyiced.rich_text()`.
+///
+/// See also
+/// --------
+/// :func:`~pyiced.span()`
+#[pyclass(name = "TextSpan", module = "pyiced")]
+#[derive(Debug, Clone)]
+pub(crate) struct WrappedTextSpan(pub TextSpan);
+
+#[pymethods]
+impl WrappedTextSpan {
+    fn __traverse__(&self, visit: pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        self.0.traverse(&visit)
+    }
+
+    fn __clear__(&mut self) {
+        self.0.link = None;
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        debug_str(&self.0)
+    }
+}
+
+#[pyfunction(name = "span")]
+/// span($module, /, text, *, color=None, font=None, size=None, underline=False, link=None)
+/// --
+///
+/// A styled run of text inside a :func:`~pyiced.rich_text()`.
+///
+/// Parameters
+/// ----------
+/// text : str
+///     The text to display.
+/// color : Optional[Color]
+///     The color of the text. Defaults to the color of the rich text.
+/// font : Optional[Font]
+///     The font of the text. Defaults to the font of the rich text.
+/// size : Optional[int]
+///     The size of the text. Defaults to the size of the rich text.
+/// underline : bool
+///     Whether to underline the text.
+/// link : Optional[object]
+///     If not None, clicking the span sends a message ``(token, link)`` to the app's
+///     :meth:`~pyiced.IcedApp.update()` method.
+///
+/// Returns
+/// -------
+/// TextSpan
+///     The newly created span.
+fn make_span(
+    text: String,
+    color: Option<&WrappedColor>,
+    font: Option<&WrappedFont>,
+    size: Option<u16>,
+    underline: Option<bool>,
+    link: Option<Py<PyAny>>,
+) -> WrappedTextSpan {
+    WrappedTextSpan(TextSpan {
+        text,
+        color: color.map(|o| o.0),
+        font: font.map(|o| o.0),
+        size,
+        underline: underline.unwrap_or(false),
+        link,
+    })
+}
+
+#[pyfunction(name = "rich_text")]
+/// rich_text($module, /, spans, *, token=None, size=None, color=None, font=None, width=None, height=None, horizontal_alignment=None)
+/// --
+///
+/// A paragraph of text with mixed styles.
+///
+/// The spans are laid out as one paragraph. Lines are broken at whitespaces and at line breaks.
+///
+/// Parameters
+/// ----------
+/// spans : Iterable[Union[None, str, TextSpan]]
+///     The runs of text to display.
+/// token : Optional[object]
+///     When the user clicks a span that has a "link", a message ``(token, link)`` is sent to the
+///     app's :meth:`~pyiced.IcedApp.update()` method.
+/// size : Optional[int]
+///     The default size of the text.
+/// color : Optional[Color]
+///     The default color of the text.
+/// font : Optional[Font]
+///     The default font of the text.
+/// width : Optional[Length]
+///     The width of the text boundaries.
+/// height : Optional[Length]
+///     The height of the text boundaries.
+/// horizontal_alignment : Optional[HorizontalAlignment]
+///     The horizontal alignment of the lines.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created rich text.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     rich_text(
+///         [
+///             span('Error: ', color=Color(1, 0, 0)),
+///             span(path, link=path, underline=True),
+///         ],
+///         token='open',
+///     )
+///
+/// See also
+/// --------
+/// :func:`~pyiced.text()`
+fn make_rich_text(
+    py: Python,
+    spans: &PyAny,
+    token: Option<Py<PyAny>>,
+    size: Option<u16>,
+    color: Option<&WrappedColor>,
+    font: Option<&WrappedFont>,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+    horizontal_alignment: Option<&WrappedHorizontalAlignment>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let spans = spans
+        .iter()?
+        .filter_map(|child| match child {
+            Ok(child) => match child.is_none() {
+                false => match child.extract() {
+                    Ok(EitherPy::Left(WrappedTextSpan(span))) => Some(span),
+                    Ok(EitherPy::Right(text)) => Some(TextSpan {
+                        text,
+                        ..Default::default()
+                    }),
+                    Err(err) => {
+                        err.print(py);
+                        None
+                    },
+                },
+                true => None,
+            },
+            Err(err) => {
+                err.print(py);
+                None
+            },
+        })
+        .collect();
+    let el = RichTextBuilder {
+        token,
+        spans,
+        size,
+        color: color.map(|o| o.0),
+        font: font.map(|o| o.0),
+        width: width.map(|o| o.0),
+        height: height.map(|o| o.0),
+        horizontal_alignment: horizontal_alignment.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for RichTextBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        let mut fragments = Vec::new();
+        for (index, span) in self.spans.iter().enumerate() {
+            split_fragments(&span.text, index, &mut fragments);
+        }
+        let spans = self
+            .spans
+            .iter()
+            .map(|span| Span {
+                color: span.color.or(self.color),
+                font: span.font.or(self.font).unwrap_or_default(),
+                size: span.size.or(self.size),
+                underline: span.underline,
+                link: span.link.clone(),
+            })
+            .collect();
+        Element::new(RichText {
+            token: self.token.clone(),
+            spans,
+            fragments,
+            width: self.width.unwrap_or(Length::Shrink),
+            height: self.height.unwrap_or(Length::Shrink),
+            horizontal_alignment: self.horizontal_alignment.unwrap_or(HorizontalAlignment::Left),
+        })
+    }
+}
+
+/// Splits a text into words with their trailing whitespaces, and line breaks.
+fn split_fragments(text: &str, span: usize, fragments: &mut Vec<Fragment>) {
+    for (line_index, line) in text.split('\n').enumerate() {
+        if line_index > 0 {
+            fragments.push(Fragment {
+                span,
+                text: String::new(),
+                word_len: 0,
+                line_break: true,
+            });
+        }
+
+        let mut rest = line.trim_end_matches('\r');
+        while !rest.is_empty() {
+            let word_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let space_len = rest[word_len..]
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len() - word_len);
+            let (text, tail) = rest.split_at(word_len + space_len);
+            fragments.push(Fragment {
+                span,
+                text: text.to_owned(),
+                word_len,
+                line_break: false,
+            });
+            rest = tail;
+        }
+    }
+}
+
+struct Span {
+    color: Option<Color>,
+    font: Font,
+    size: Option<u16>,
+    underline: bool,
+    link: Option<Py<PyAny>>,
+}
+
+struct Fragment {
+    span: usize,
+    text: String,
+    word_len: usize,
+    line_break: bool,
+}
+
+struct RichText {
+    token: Option<Py<PyAny>>,
+    spans: Vec<Span>,
+    fragments: Vec<Fragment>,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+}
+
+impl RichText {
+    fn size(&self, renderer: &Renderer, span: &Span) -> u16 {
+        span.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn link_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<&Py<PyAny>> {
+        self.fragments
+            .iter()
+            .zip(layout.children())
+            .find(|(_, layout)| layout.bounds().contains(cursor_position))
+            .and_then(|(fragment, _)| self.spans[fragment.span].link.as_ref())
+    }
+}
+
+fn hash_font(font: &Font, state: &mut Hasher) {
+    match font {
+        Font::Default => 0u8.hash(state),
+        Font::External { name, .. } => {
+            1u8.hash(state);
+            name.hash(state);
+        },
+    }
+}
+
+impl Widget<Message, Renderer> for RichText {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max_width = limits.max().width;
+
+        // lines as (first node, end node, width without trailing whitespaces, height)
+        let mut nodes: Vec<Node> = Vec::with_capacity(self.fragments.len());
+        let mut lines: Vec<(usize, usize, f32, f32)> = Vec::new();
+        let mut line_start = 0;
+        let mut line_width = 0.0f32;
+        let mut line_height = 0.0f32;
+        let mut x = 0.0f32;
+        for fragment in self.fragments.iter() {
+            let span = &self.spans[fragment.span];
+            let size = self.size(renderer, span);
+            let (advance, height) =
+                renderer.measure(&fragment.text, size, span.font, Size::INFINITY);
+            let word_width = match fragment.word_len == fragment.text.len() {
+                true => advance,
+                false => {
+                    let word = &fragment.text[..fragment.word_len];
+                    renderer.measure(word, size, span.font, Size::INFINITY).0
+                },
+            };
+            let height = height.max(size as f32);
+
+            if fragment.line_break || (line_start < nodes.len() && x + word_width > max_width) {
+                lines.push((line_start, nodes.len(), line_width, line_height));
+                line_start = nodes.len();
+                line_width = 0.0;
+                line_height = 0.0;
+                x = 0.0;
+            }
+            if fragment.line_break {
+                line_height = height;
+                nodes.push(Node::new(Size::new(0.0, height)));
+                continue;
+            }
+
+            let mut node = Node::new(Size::new(advance, height));
+            node.move_to(Point::new(x, 0.0));
+            nodes.push(node);
+            line_width = line_width.max(x + word_width);
+            line_height = line_height.max(height);
+            x += advance;
+        }
+        lines.push((line_start, nodes.len(), line_width, line_height));
+
+        let content_width = lines.iter().fold(0.0f32, |acc, line| acc.max(line.2));
+        let align_width = match max_width.is_finite() {
+            true => max_width,
+            false => content_width,
+        };
+        let mut y = 0.0f32;
+        for &(start, end, line_width, line_height) in lines.iter() {
+            let dx = match self.horizontal_alignment {
+                HorizontalAlignment::Left => 0.0,
+                HorizontalAlignment::Center => ((align_width - line_width) / 2.0).max(0.0),
+                HorizontalAlignment::Right => (align_width - line_width).max(0.0),
+            };
+            for node in nodes[start..end].iter_mut() {
+                // align the bottoms of the fragments of different sizes
+                let bounds = node.bounds();
+                node.move_to(Point::new(bounds.x + dx, y + line_height - bounds.height));
+            }
+            y += line_height;
+        }
+
+        let size = limits.resolve(Size::new(content_width, y));
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let mut primitives = Vec::with_capacity(self.fragments.len());
+        for (fragment, layout) in self.fragments.iter().zip(layout.children()) {
+            if fragment.line_break {
+                continue;
+            }
+
+            let span = &self.spans[fragment.span];
+            let bounds = layout.bounds();
+            let size = self.size(renderer, span);
+            let color = span.color.unwrap_or(defaults.text.color);
+            primitives.push(Primitive::Text {
+                content: fragment.text.clone(),
+                bounds,
+                color,
+                size: size as f32,
+                font: span.font,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            });
+            if span.underline {
+                let thickness = (size as f32 / 14.0).max(1.0).round();
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y: bounds.y + bounds.height - thickness,
+                        width: bounds.width,
+                        height: thickness,
+                    },
+                    background: Background::Color(color),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+
+        let interaction = match self.link_at(layout, cursor_position) {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        (self.horizontal_alignment as u8).hash(state);
+        for span in self.spans.iter() {
+            span.size.hash(state);
+            hash_font(&span.font, state);
+        }
+        for fragment in self.fragments.iter() {
+            fragment.span.hash(state);
+            fragment.text.hash(state);
+            fragment.line_break.hash(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let link = match self.link_at(layout, cursor_position) {
+            Some(link) => link,
+            None => return event::Status::Ignored,
+        };
+        let message = Python::with_gil(|py| (self.token.clone(), link.clone()).into_py(py));
+        messages.push(Message::Python(message));
+        event::Status::Captured
+    }
+}
@@ -0,0 +1,104 @@
+use iced::{Element, Font, Length, TextInput};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::assign;
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{text_input_with_state, TextInputState, WrappedTextInputState};
+use crate::styles::{TextInputStyleSheet, WrappedTextInputStyleSheet};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_selectable_text, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SelectableTextBuilder {
+    pub state: TextInputState,
+    pub value: String,
+    pub font: Option<Font>,
+    pub width: Option<Length>,
+    pub max_width: Option<u32>,
+    pub padding: Option<u16>,
+    pub size: Option<u16>,
+    pub style: Option<TextInputStyleSheet>,
+}
+
+impl GCProtocol for SelectableTextBuilder {}
+
+#[pyfunction(name = "selectable_text")]
+/// selectable_text($module, /, state, value, *, font=None, width=None, max_width=None, padding=None, size=None, style=None)
+/// --
+///
+/// A single line of text that the user can select and copy, but not change.
+///
+/// The text can be selected with the mouse and the keyboard. A double click selects a word,
+/// a triple click selects the whole text. Ctrl+C copies the selection into the clipboard.
+///
+/// Parameters
+/// ----------
+/// state : TextInputState
+///     Current state of the element. The same object must be given between calls.
+///     Use :meth:`~pyiced.TextInputState.selection()` to query the selected text.
+/// value : str
+///     The text to display.
+/// font : Optional[Font]
+///     The font of the text.
+/// width : Optional[Length]
+///     The width of the element.
+/// max_width : Optional[int]
+///     The maximum width of the element.
+/// padding : Optional[int]
+///     The padding of the element.
+/// size : Optional[int]
+///      The text size of the element.
+/// style : Optional[TextInputStyleSheet]
+///     Style of the element. Defaults to a style without background and border.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created selectable text.
+///
+/// See also
+/// --------
+/// * :func:`~pyiced.text()`
+/// * :func:`~pyiced.text_input()`
+fn make_selectable_text(
+    state: &WrappedTextInputState,
+    value: String,
+    font: Option<&WrappedFont>,
+    width: Option<&WrappedLength>,
+    max_width: Option<u32>,
+    padding: Option<u16>,
+    size: Option<u16>,
+    style: Option<&WrappedTextInputStyleSheet>,
+) -> WrappedWidgetBuilder {
+    let el = SelectableTextBuilder {
+        state: state.0.clone(),
+        value,
+        font: font.map(|o| o.0),
+        width: width.map(|o| o.0),
+        max_width,
+        padding,
+        size,
+        style: style.map(|o| o.0),
+    };
+    el.into()
+}
+
+impl ToNative for SelectableTextBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        // Edits are discarded, so the value stays the same.
+        let on_change = |_| Message::None;
+        let style = self.style.unwrap_or_else(TextInputStyleSheet::plain);
+
+        text_input_with_state(&self.state, move |state| {
+            let el = TextInput::new(state, "", &self.value, on_change);
+            let el = assign!(el, self, font, width, max_width, padding, size);
+            Ok(el.style(style))
+        })
+    }
+}
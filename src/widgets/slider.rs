@@ -1,20 +1,74 @@
-use iced::{Element, Length, Slider};
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::slider::{HandleShape, StyleSheet};
+use iced::{Color, Element, Length, Point, Rectangle, Size};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::{event, keyboard, mouse, touch, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 
-use crate::assign;
-use crate::common::{some_err, validate_f32, GCProtocol, Message, ToNative};
-use crate::states::{slider_with_state, SliderState, WrappedSliderState};
+use crate::common::{some_err, validate_f32, EitherPy, GCProtocol, Message, ToNative};
+use crate::states::{SlideState, SliderState, WrappedSliderState};
 use crate::styles::{SliderStyleSheet, WrappedSliderStyleSheet};
 use crate::widgets::WrappedWidgetBuilder;
 use crate::wrapped::WrappedLength;
+use crate::{format_to_string_ignore, make_with_state};
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(make_slider, m)?)?;
     Ok(())
 }
 
+/// The default thickness of a slider, like in iced.
+const DEFAULT_THICKNESS: u16 = 22;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    pub(crate) fn parse(orientation: Option<&str>) -> PyResult<Self> {
+        match orientation.unwrap_or("horizontal") {
+            "horizontal" => Ok(Orientation::Horizontal),
+            "vertical" => Ok(Orientation::Vertical),
+            s => Err(PyErr::new::<PyValueError, _>(format_to_string_ignore!(
+                "Unknown orientation value: {:#}",
+                s,
+            ))),
+        }
+    }
+
+    /// The width and height of a slider, if not given by the user.
+    pub(crate) fn size(
+        self,
+        width: Option<&WrappedLength>,
+        height: Option<EitherPy<u16, WrappedLength>>,
+    ) -> (Length, Length) {
+        let height = height.map(|height| match height {
+            EitherPy::Left(units) => Length::Units(units),
+            EitherPy::Right(length) => length.0,
+        });
+        let width = width.map(|o| o.0);
+        match self {
+            Orientation::Horizontal => (
+                width.unwrap_or(Length::Fill),
+                height.unwrap_or(Length::Units(DEFAULT_THICKNESS)),
+            ),
+            Orientation::Vertical => (
+                width.unwrap_or(Length::Units(DEFAULT_THICKNESS)),
+                height.unwrap_or(Length::Fill),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct SliderBuilder {
     pub token: Py<PyAny>,
@@ -22,31 +76,48 @@ pub(crate) struct SliderBuilder {
     pub start: f32,
     pub end: f32,
     pub value: f32,
-    pub width: Option<Length>,
-    pub height: Option<u16>,
+    pub width: Length,
+    pub height: Length,
     pub step: Option<f32>,
+    pub orientation: Orientation,
+    pub release_only: bool,
+    pub on_drag_start: Option<Py<PyAny>>,
+    pub on_release: Option<Py<PyAny>>,
     pub style: Option<SliderStyleSheet>,
 }
 
 impl GCProtocol for SliderBuilder {
     fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
         visit.call(&self.token)?;
+        if let Some(on_drag_start) = &self.on_drag_start {
+            visit.call(on_drag_start)?;
+        }
+        if let Some(on_release) = &self.on_release {
+            visit.call(on_release)?;
+        }
         Ok(())
     }
 }
 
 #[pyfunction(name = "slider")]
-/// slider($module, /, token, state, start, end, value, step=1.0, *, width=None, height=None, style=None)
+/// slider($module, /, token, state, start, end, value, step=1.0, *, width=None, height=None, orientation="horizontal", release_only=False, on_drag_start=None, on_release=None, style=None)
 /// --
 ///
-/// An horizontal bar and a handle that selects a single value from a range of values.
+/// A bar and a handle that selects a single value from a range of values.
+///
+/// A clicked slider has the keyboard focus, and is drawn in its hovered style.
+/// Its value can be changed with the arrow keys, with page up and page down (ten steps),
+/// and with home and end. While the mouse cursor is over the focused slider, its value can also be changed
+/// with the mouse wheel. The mouse wheel does not affect sliders without focus, so they can be put into a
+/// :func:`~pyiced.scrollable()`.
+/// Changes with the keyboard and the mouse wheel are always sent immediately.
 ///
 /// Parameters
 /// ----------
 /// token : object
 ///     When the user select a value, a message ``(token, new_value)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
 ///
-///     When the user releases the pressed slider ``(token, None, 'release')`` is sent.
+///     When the user releases the pressed slider ``(token, None, 'release')`` is sent, unless ``on_release`` is given.
 /// state : SliderState
 ///     Current state of the slider. The same object must be given between calls.
 /// start : float
@@ -58,9 +129,18 @@ impl GCProtocol for SliderBuilder {
 /// step : float
 ///     Step size of the slider.
 /// width : Optional[Length]
-///     Width of the slider.
-/// height : Optional[int]
-///     Height of the slider.
+///     Width of the slider. Defaults to Length.FILL for an horizontal slider, and 22 units for a vertical slider.
+/// height : Union[int, Length, None]
+///     Height of the slider. Defaults to 22 units for an horizontal slider, and Length.FILL for a vertical slider.
+/// orientation : Literal['horizontal', 'vertical']
+///     The direction of the bar. The start of a vertical slider is at the bottom.
+/// release_only : bool
+///     If True, then ``(token, new_value)`` is only sent once when the user releases the slider,
+///     and not every time the handle is moved.
+/// on_drag_start : Optional[object]
+///     When the user starts to drag the slider, a message ``(on_drag_start, value)`` is sent with the value before the drag.
+/// on_release : Optional[object]
+///     When the user releases the slider, a message ``(on_release, value)`` is sent with the final value.
 /// style : SliderStyleSheet
 ///     The normal style of the slider.
 ///
@@ -80,7 +160,8 @@ impl GCProtocol for SliderBuilder {
 ///
 /// See also
 /// --------
-/// `iced_native::widget::slider::Slider <https://docs.rs/iced_native/0.4.0/iced_native/widget/slider/struct.Slider.html>`_
+/// * :func:`~pyiced.range_slider()`
+/// * `iced_native::widget::slider::Slider <https://docs.rs/iced_native/0.4.0/iced_native/widget/slider/struct.Slider.html>`_
 fn make_slider(
     token: Py<PyAny>,
     state: &WrappedSliderState,
@@ -89,7 +170,11 @@ fn make_slider(
     value: f32,
     step: Option<f32>,
     width: Option<&WrappedLength>,
-    height: Option<u16>,
+    height: Option<EitherPy<u16, WrappedLength>>,
+    orientation: Option<&str>,
+    release_only: Option<bool>,
+    on_drag_start: Option<Py<PyAny>>,
+    on_release: Option<Py<PyAny>>,
     style: Option<&WrappedSliderStyleSheet>,
 ) -> PyResult<WrappedWidgetBuilder> {
     let start = validate_f32(start)?;
@@ -102,32 +187,419 @@ fn make_slider(
         ));
     }
 
+    let orientation = Orientation::parse(orientation)?;
+    let (width, height) = orientation.size(width, height);
     let el = SliderBuilder {
         token,
         state: state.0.clone(),
         start,
         end,
         value,
-        width: width.map(|o| o.0),
+        width,
         height,
         step: some_err(step.map(validate_f32))?,
+        orientation,
+        release_only: release_only.unwrap_or(false),
+        on_drag_start,
+        on_release,
         style: style.map(|o| o.0),
     };
     Ok(el.into())
 }
 
 impl ToNative for SliderBuilder {
-    fn to_native(&self, py: Python) -> Element<'static, Message> {
-        let token = self.token.clone();
-        let on_release = Message::Python((&token, (), "release").into_py(py));
-        let on_change =
-            move |value| Python::with_gil(|py| Message::Python((token.clone(), value).into_py(py)));
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
         slider_with_state(&self.state, |state| {
-            let range = self.start..=self.end;
-            let el = Slider::new(state, range, self.value, on_change);
-            let el = assign!(el, self, width, height, step, style);
-            let el = el.on_release(on_release.clone());
-            Ok(el)
+            state.fit(1);
+            Ok(Slider {
+                state,
+                token: self.token.clone(),
+                start: self.start,
+                end: self.end,
+                values: vec![self.value],
+                step: self.step.unwrap_or(1.0),
+                width: self.width,
+                height: self.height,
+                orientation: self.orientation,
+                release_only: self.release_only,
+                on_drag_start: self.on_drag_start.clone(),
+                on_release: self.on_release.clone(),
+                legacy_release: self.on_release.is_none(),
+                style: self.style,
+            })
         })
     }
 }
+
+make_with_state! {
+    slider_with_state(
+        crate::widgets::slider::Slider,
+        crate::widgets::slider::Slider<'this>,
+        crate::states::SlideState,
+    );
+}
+
+/// A slider with one handle, or with two handles for a range.
+pub(crate) struct Slider<'a> {
+    pub state: &'a mut SlideState,
+    pub token: Py<PyAny>,
+    pub start: f32,
+    pub end: f32,
+    /// The value, or the low and the high value of a range.
+    pub values: Vec<f32>,
+    pub step: f32,
+    pub width: Length,
+    pub height: Length,
+    pub orientation: Orientation,
+    pub release_only: bool,
+    pub on_drag_start: Option<Py<PyAny>>,
+    pub on_release: Option<Py<PyAny>>,
+    /// Whether to send `(token, None, 'release')`, like slider() did before it had on_release.
+    pub legacy_release: bool,
+    pub style: Option<SliderStyleSheet>,
+}
+
+impl Slider<'_> {
+    /// The values that are shown, i.e. the dragged values if they were not sent yet.
+    fn current_values(&self) -> Vec<f32> {
+        match &self.state.pending {
+            Some(values) => values.clone(),
+            None => self.values.clone(),
+        }
+    }
+
+    /// The value under the cursor, rounded to a step.
+    fn value_at(&self, bounds: Rectangle, cursor_position: Point) -> f32 {
+        let percent = match self.orientation {
+            Orientation::Horizontal => (cursor_position.x - bounds.x) / bounds.width,
+            Orientation::Vertical => (bounds.y + bounds.height - cursor_position.y) / bounds.height,
+        };
+        if percent <= 0.0 {
+            self.start
+        } else if percent >= 1.0 {
+            self.end
+        } else {
+            self.snap(self.start + percent * (self.end - self.start))
+        }
+    }
+
+    /// Rounds the value to a step, and keeps it inside the range.
+    fn snap(&self, value: f32) -> f32 {
+        let value = match self.step > 0.0 {
+            true => ((value - self.start) / self.step).round() * self.step + self.start,
+            false => value,
+        };
+        value.clamp(self.start, self.end)
+    }
+
+    /// The step size for the keyboard and the mouse wheel.
+    fn key_step(&self) -> f32 {
+        match self.step > 0.0 {
+            true => self.step,
+            false => (self.end - self.start) / 100.0,
+        }
+    }
+
+    /// The handle that is moved if the user clicks at the value.
+    fn nearest_handle(&self, value: f32) -> usize {
+        match self.current_values()[..] {
+            [low, high] if value > low && (value >= high || high - value <= value - low) => 1,
+            _ => 0,
+        }
+    }
+
+    fn message(&self, token: &Py<PyAny>, values: &[f32]) -> Message {
+        Python::with_gil(|py| match *values {
+            [low, high] => Message::Python((token, (low, high)).into_py(py)),
+            _ => Message::Python((token, values[0]).into_py(py)),
+        })
+    }
+
+    fn press(&mut self, handle: usize, value: f32, messages: &mut Vec<Message>) {
+        if let Some(token) = &self.on_drag_start {
+            messages.push(self.message(token, &self.current_values()));
+        }
+        self.state.dragging = Some(handle);
+        self.change(handle, value, messages);
+    }
+
+    /// Moves the dragged handle to the value.
+    fn change(&mut self, handle: usize, value: f32, messages: &mut Vec<Message>) {
+        let old_values = self.current_values();
+        let mut values = old_values.clone();
+        values[handle] = value;
+        if let [low, high] = values[..] {
+            // the handles change their roles if one is dragged over the other
+            if low > high {
+                values.swap(0, 1);
+                self.state.dragging = Some(1 - handle);
+            }
+        }
+        if values == old_values {
+            return;
+        }
+        match self.release_only {
+            true => self.state.pending = Some(values),
+            false => messages.push(self.message(&self.token, &values)),
+        }
+    }
+
+    fn release(&mut self, messages: &mut Vec<Message>) {
+        // the last dragged handle keeps the keyboard focus
+        self.state.focused = self.state.dragging.take();
+        let values = match self.state.pending.take() {
+            Some(values) if values != self.values => {
+                messages.push(self.message(&self.token, &values));
+                values
+            },
+            _ => self.values.clone(),
+        };
+        if let Some(token) = &self.on_release {
+            messages.push(self.message(token, &values));
+        } else if self.legacy_release {
+            let message = Python::with_gil(|py| (&self.token, (), "release").into_py(py));
+            messages.push(Message::Python(message));
+        }
+    }
+
+    /// Moves a handle with the keyboard or the mouse wheel. The handles of a range cannot pass
+    /// each other this way, and the new value is sent immediately, even if `release_only`.
+    fn set(&mut self, handle: usize, value: f32, messages: &mut Vec<Message>) {
+        let mut values = self.values.clone();
+        let (min, max) = match (handle, &values[..]) {
+            (0, &[_, high]) => (self.start, high),
+            (1, &[low, _]) => (low, self.end),
+            _ => (self.start, self.end),
+        };
+        values[handle] = self.snap(value).clamp(min, max);
+        if values != self.values {
+            messages.push(self.message(&self.token, &values));
+        }
+    }
+
+    /// A rectangle, measured along and across the slider. Vertical sliders start at the bottom.
+    fn rect(
+        &self,
+        bounds: Rectangle,
+        along: f32,
+        length: f32,
+        across: f32,
+        thickness: f32,
+    ) -> Rectangle {
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + along,
+                y: bounds.y + across,
+                width: length,
+                height: thickness,
+            },
+            Orientation::Vertical => Rectangle {
+                x: bounds.x + across,
+                y: bounds.y + bounds.height - along - length,
+                width: thickness,
+                height: length,
+            },
+        }
+    }
+}
+
+impl Widget<Message, Renderer> for Slider<'_> {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_dragging = self.state.dragging.is_some();
+
+        let style_sheet: Box<dyn StyleSheet> = match self.style {
+            Some(style) => Box::new(style),
+            None => Default::default(),
+        };
+        let style = if is_dragging {
+            style_sheet.dragging()
+        } else if is_mouse_over || self.state.focused.is_some() {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let (length, thickness) = match self.orientation {
+            Orientation::Horizontal => (bounds.width, bounds.height),
+            Orientation::Vertical => (bounds.height, bounds.width),
+        };
+        let (handle_length, handle_thickness, handle_border_radius) = match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), thickness, border_radius),
+        };
+        let rail = (thickness / 2.0).round();
+        let quad = |bounds, color: Color| Primitive::Quad {
+            bounds,
+            background: color.into(),
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let mut primitives = vec![
+            quad(
+                self.rect(bounds, 0.0, length, rail, 2.0),
+                style.rail_colors.0,
+            ),
+            quad(
+                self.rect(bounds, 0.0, length, rail + 2.0, 2.0),
+                style.rail_colors.1,
+            ),
+        ];
+
+        let offsets: Vec<f32> = self
+            .current_values()
+            .iter()
+            .map(|&value| match self.start >= self.end {
+                true => 0.0,
+                false => {
+                    let offset =
+                        (length - handle_length) * (value - self.start) / (self.end - self.start);
+                    offset.round()
+                },
+            })
+            .collect();
+        if let [low, high] = offsets[..] {
+            // the selected range is drawn with the handle's border color to be visible on the rail
+            let selection = self.rect(bounds, low + handle_length / 2.0, high - low, rail, 4.0);
+            primitives.push(quad(selection, style.handle.border_color));
+        }
+        for offset in offsets {
+            primitives.push(Primitive::Quad {
+                bounds: self.rect(
+                    bounds,
+                    offset,
+                    handle_length,
+                    rail - handle_thickness / 2.0,
+                    handle_thickness,
+                ),
+                background: style.handle.color.into(),
+                border_radius: handle_border_radius,
+                border_width: style.handle.border_width,
+                border_color: style.handle.border_color,
+            });
+        }
+
+        let interaction = if is_dragging {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    self.state.focused = None;
+                    return event::Status::Ignored;
+                }
+                let value = self.value_at(bounds, cursor_position);
+                let handle = self.nearest_handle(value);
+                self.press(handle, value, messages);
+                event::Status::Captured
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match self.state.dragging {
+                Some(handle) => {
+                    let value = self.value_at(bounds, cursor_position);
+                    self.change(handle, value, messages);
+                    event::Status::Captured
+                },
+                None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if self.state.dragging.is_some() =>
+            {
+                self.release(messages);
+                event::Status::Captured
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) && self.state.dragging.is_none() =>
+            {
+                let handle = match self.state.focused {
+                    Some(handle) => handle,
+                    None => return event::Status::Ignored,
+                };
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                if y == 0.0 {
+                    return event::Status::Ignored;
+                }
+                let value = self.values[handle] + y.signum() * self.key_step();
+                self.set(handle, value, messages);
+                event::Status::Captured
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+                if self.state.dragging.is_none() =>
+            {
+                let handle = match self.state.focused {
+                    Some(handle) => handle,
+                    None => return event::Status::Ignored,
+                };
+                let value = self.values[handle];
+                let step = self.key_step();
+                let value = match key_code {
+                    KeyCode::Left | KeyCode::Down => value - step,
+                    KeyCode::Right | KeyCode::Up => value + step,
+                    KeyCode::PageDown => value - 10.0 * step,
+                    KeyCode::PageUp => value + 10.0 * step,
+                    KeyCode::Home => self.start,
+                    KeyCode::End => self.end,
+                    _ => return event::Status::Ignored,
+                };
+                self.set(handle, value, messages);
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+}
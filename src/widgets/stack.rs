@@ -0,0 +1,310 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::{Align, Element, Length, Point, Rectangle, Size, Vector};
+use iced_native::layout::{Limits, Node};
+use iced_native::{event, mouse, overlay, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{validate_f32, GCProtocol, Message, ToNative};
+use crate::widgets::{WidgetBuilder, WrappedWidgetBuilder};
+use crate::wrapped::{WrappedAlign, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_stack, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StackBuilder {
+    pub children: Vec<StackLayer>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub align_x: Option<Align>,
+    pub align_y: Option<Align>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StackLayer {
+    pub child: WidgetBuilder,
+    pub align_x: Option<Align>,
+    pub align_y: Option<Align>,
+    pub offset: Option<Vector>,
+}
+
+impl GCProtocol for StackBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        for layer in self.children.iter() {
+            layer.child.traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+type LayerTuple = (
+    WrappedWidgetBuilder,
+    Option<WrappedAlign>,
+    Option<WrappedAlign>,
+    Option<(f32, f32)>,
+);
+
+fn extract_layer(child: &PyAny) -> PyResult<StackLayer> {
+    if let Ok(WrappedWidgetBuilder(child)) = child.extract() {
+        return Ok(StackLayer {
+            child,
+            ..Default::default()
+        });
+    }
+    let (WrappedWidgetBuilder(child), align_x, align_y, offset) =
+        match child.extract::<(_, _, _)>() {
+            Ok((child, align_x, align_y)) => (child, align_x, align_y, None),
+            Err(_) => child.extract::<LayerTuple>()?,
+        };
+    let offset = match offset {
+        Some((x, y)) => Some(Vector::new(validate_f32(x)?, validate_f32(y)?)),
+        None => None,
+    };
+    Ok(StackLayer {
+        child,
+        align_x: align_x.map(|o: WrappedAlign| o.0),
+        align_y: align_y.map(|o: WrappedAlign| o.0),
+        offset,
+    })
+}
+
+#[pyfunction(name = "stack")]
+/// stack($module, /, children, *, width=None, height=None, align_x=None, align_y=None)
+/// --
+///
+/// A container that places all its children on top of each other.
+///
+/// The first child is the bottommost layer, the last child is the topmost layer.
+/// Events are forwarded top-down, so the topmost interactive child captures them.
+///
+/// Parameters
+/// ----------
+/// children : Iterable[Union[None, Element, Tuple[Element, Optional[Align], Optional[Align]], Tuple[Element, Optional[Align], Optional[Align], Optional[Tuple[float, float]]]]]
+///     The layers of the stack.
+///
+///     A layer is either simply an element, or a tuple ``(element, align_x, align_y)``, or
+///     ``(element, align_x, align_y, (offset_x, offset_y))``. The alignment overrides the
+///     stack's alignment for this layer. The offset is added to the layer's position after
+///     it was aligned.
+/// width : Optional[Length]
+///     Width of the stack.
+/// height : Optional[Length]
+///     Height of the stack.
+/// align_x : Optional[Align]
+///     Default horizontal alignment of the layers. Defaults to "start".
+/// align_y : Optional[Align]
+///     Default vertical alignment of the layers. Defaults to "start".
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created stack.
+fn make_stack(
+    py: Python,
+    children: &PyAny,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+    align_x: Option<&WrappedAlign>,
+    align_y: Option<&WrappedAlign>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let children = children
+        .iter()?
+        .filter_map(|child| match child {
+            Ok(child) => match child.is_none() {
+                false => match extract_layer(child) {
+                    Ok(layer) => Some(layer),
+                    Err(err) => {
+                        err.print(py);
+                        None
+                    },
+                },
+                true => None,
+            },
+            Err(err) => {
+                err.print(py);
+                None
+            },
+        })
+        .collect();
+    let el = StackBuilder {
+        children,
+        width: width.map(|o| o.0),
+        height: height.map(|o| o.0),
+        align_x: align_x.map(|o| o.0),
+        align_y: align_y.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for StackBuilder {
+    fn to_native(&self, py: Python) -> Element<'static, Message> {
+        let default_x = self.align_x.unwrap_or(Align::Start);
+        let default_y = self.align_y.unwrap_or(Align::Start);
+        let layers = self
+            .children
+            .iter()
+            .map(|layer| Layer {
+                element: layer.child.to_native(py),
+                align_x: layer.align_x.unwrap_or(default_x),
+                align_y: layer.align_y.unwrap_or(default_y),
+                offset: layer.offset.unwrap_or_else(|| Vector::new(0.0, 0.0)),
+            })
+            .collect();
+        Element::new(Stack {
+            layers,
+            width: self.width.unwrap_or(Length::Shrink),
+            height: self.height.unwrap_or(Length::Shrink),
+        })
+    }
+}
+
+struct Layer {
+    element: Element<'static, Message>,
+    align_x: Align,
+    align_y: Align,
+    offset: Vector,
+}
+
+struct Stack {
+    layers: Vec<Layer>,
+    width: Length,
+    height: Length,
+}
+
+impl Widget<Message, Renderer> for Stack {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let child_limits = limits.loose();
+
+        let mut nodes: Vec<Node> = self
+            .layers
+            .iter()
+            .map(|layer| layer.element.layout(renderer, &child_limits))
+            .collect();
+        let intrinsic_size = nodes.iter().fold(Size::ZERO, |acc, node| {
+            let size = node.size();
+            Size::new(acc.width.max(size.width), acc.height.max(size.height))
+        });
+        let size = limits.resolve(intrinsic_size);
+
+        for (node, layer) in nodes.iter_mut().zip(self.layers.iter()) {
+            node.move_to(Point::new(layer.offset.x, layer.offset.y));
+            node.align(layer.align_x, layer.align_y, size);
+        }
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let mut interaction = mouse::Interaction::default();
+        let primitives = self
+            .layers
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .map(|(index, (layer, layout))| {
+                let (primitive, layer_interaction) =
+                    layer
+                        .element
+                        .draw(renderer, defaults, layout, cursor_position, viewport);
+                if layer_interaction != mouse::Interaction::default() {
+                    // the topmost layer wins
+                    interaction = layer_interaction;
+                }
+                match index {
+                    0 => primitive,
+                    // The renderer draws all quads of a layer before its images and texts,
+                    // so every upper layer needs a render layer of its own.
+                    _ => Primitive::Clip {
+                        bounds: *viewport,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(primitive),
+                    },
+                }
+            })
+            .collect();
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        for layer in self.layers.iter() {
+            layer.align_x.hash(state);
+            layer.align_y.hash(state);
+            layer.offset.x.to_bits().hash(state);
+            layer.offset.y.to_bits().hash(state);
+            layer.element.hash_layout(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        // Lower layers still see clicks and cursor moves after an upper layer captured them,
+        // but with the cursor out of their bounds, e.g. so that a text input loses its focus.
+        let forward = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::CursorMoved { .. })
+        );
+
+        let mut status = event::Status::Ignored;
+        let layouts: Vec<_> = layout.children().collect();
+        for (layer, layout) in self.layers.iter_mut().zip(layouts).rev() {
+            let cursor_position = match status {
+                event::Status::Ignored => cursor_position,
+                event::Status::Captured if forward => Point::new(-1.0, -1.0),
+                event::Status::Captured => break,
+            };
+            let layer_status = layer.element.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            );
+            status = status.merge(layer_status);
+        }
+        status
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let layouts: Vec<_> = layout.children().collect();
+        self.layers
+            .iter_mut()
+            .zip(layouts)
+            .rev()
+            .find_map(|(layer, layout)| layer.element.overlay(layout))
+    }
+}
@@ -5,8 +5,9 @@ use pyo3::wrap_pyfunction;
 
 use crate::assign;
 use crate::common::{GCProtocol, Message, ToNative};
+use crate::widgets::image::{Picture, PictureOptions, Source};
 use crate::widgets::WrappedWidgetBuilder;
-use crate::wrapped::{WrappedLength, WrappedSvgHandle};
+use crate::wrapped::{WrappedColor, WrappedLength, WrappedSvgHandle};
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(make_svg, m)?)?;
@@ -18,12 +19,13 @@ pub(crate) struct SvgBuilder {
     pub handle: Handle,
     pub width: Option<Length>,
     pub height: Option<Length>,
+    pub options: Option<PictureOptions>,
 }
 
 impl GCProtocol for SvgBuilder {}
 
 #[pyfunction(name = "svg")]
-/// svg($module, /, handle, *, width=None, height=None)
+/// svg($module, /, handle, *, width=None, height=None, content_fit=None, filter=None, opacity=None, rotation=None, color=None)
 /// --
 ///
 /// A vector graphics image.
@@ -40,6 +42,19 @@ impl GCProtocol for SvgBuilder {}
 ///     The width of the image.
 /// heigth : Optional[Length]
 ///     The height of the image.
+/// content_fit : Optional[str]
+///     How the image is fitted into the frame: "contain" (default), "cover", "fill", "none" or
+///     "scale_down". See :func:`~pyiced.image()`.
+/// filter : Optional[str]
+///     "linear" (default) renders the image in the resolution of the frame.
+///     "nearest" renders it in its natural size, and scales it without smoothing.
+/// opacity : Optional[float]
+///     The opacity of the image between 0.0 and 1.0 (default).
+/// rotation : Optional[float]
+///     Clockwise rotation of the image in degrees.
+/// color : Optional[Color]
+///     Paint the image in a single color, e.g. to make a monochrome icon follow the theme.
+///     The transparency of the image is kept.
 ///
 /// Returns
 /// -------
@@ -55,6 +70,12 @@ impl GCProtocol for SvgBuilder {}
 /// .. literalinclude :: ../examples/widgets/svg.py
 ///    :language: python
 ///
+/// Notes
+/// -----
+/// If filter, opacity, rotation or color is set, then the image is rendered on the CPU in a
+/// background thread, so it can appear with a short delay.
+/// The results are cached in a few sizes, but changing the values often is costly.
+///
 /// See also
 /// --------
 /// `iced_native::widget::svg::Svg <https://docs.rs/iced_native/0.4.0/iced_native/widget/svg/struct.Svg.html>`_
@@ -62,17 +83,31 @@ fn make_svg(
     handle: &WrappedSvgHandle,
     width: Option<&WrappedLength>,
     height: Option<&WrappedLength>,
-) -> WrappedWidgetBuilder {
+    content_fit: Option<&str>,
+    filter: Option<&str>,
+    opacity: Option<f32>,
+    rotation: Option<f32>,
+    color: Option<&WrappedColor>,
+) -> PyResult<WrappedWidgetBuilder> {
     let el = SvgBuilder {
         handle: handle.0.clone(),
         width: width.map(|o| o.0),
         height: height.map(|o| o.0),
+        options: PictureOptions::new(content_fit, filter, opacity, rotation, color.map(|o| o.0))?,
     };
-    el.into()
+    Ok(el.into())
 }
 
 impl ToNative for SvgBuilder {
     fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        if let Some(options) = self.options {
+            return Element::new(Picture {
+                source: Source::Svg(self.handle.clone()),
+                options,
+                width: self.width.unwrap_or(Length::Shrink),
+                height: self.height.unwrap_or(Length::Shrink),
+            });
+        }
         let el = Svg::new(self.handle.clone());
         let el = assign!(el, self, width, height);
         el.into()
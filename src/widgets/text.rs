@@ -1,12 +1,28 @@
-use iced::{Color, Element, Font, HorizontalAlignment, Length, Text, VerticalAlignment};
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::hash::Hash;
+
+use iced::tooltip::Position;
+use iced::{
+    Color, Element, Font, HorizontalAlignment, Length, Point, Rectangle, Size, Text,
+    VerticalAlignment,
+};
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{mouse, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::assign;
 use crate::common::{GCProtocol, Message, ToNative};
-use crate::widgets::WrappedWidgetBuilder;
+use crate::widgets::{TooltipBuilder, WrappedWidgetBuilder};
 use crate::wrapped::{
-    WrappedColor, WrappedFont, WrappedHorizontalAlignment, WrappedLength, WrappedVerticalAlignment,
+    WrappedColor, WrappedFont, WrappedHorizontalAlignment, WrappedLength, WrappedTooltipPosition,
+    WrappedVerticalAlignment,
 };
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -24,12 +40,29 @@ pub(crate) struct TextBuilder {
     pub height: Option<Length>,
     pub horizontal_alignment: Option<HorizontalAlignment>,
     pub vertical_alignment: Option<VerticalAlignment>,
+    pub max_lines: Option<usize>,
+    pub wrap: Option<TextWrap>,
+    pub ellipsis: Option<Ellipsis>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum TextWrap {
+    Word,
+    Char,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Ellipsis {
+    Start,
+    Middle,
+    End,
 }
 
 impl GCProtocol for TextBuilder {}
 
 #[pyfunction(name = "text")]
-/// text($module, /, label, *, size=None, color=None, font=None, width=None, height=None, horizontal_alignment=None, vertical_alignment=None)
+/// text($module, /, label, *, size=None, color=None, font=None, width=None, height=None, horizontal_alignment=None, vertical_alignment=None, max_lines=None, wrap=None, ellipsis=None, tooltip=None)
 /// --
 ///
 /// A paragraph of text.
@@ -52,12 +85,32 @@ impl GCProtocol for TextBuilder {}
 ///     The horizontal alignment of the text.
 /// vertical_alignment : Optional[VerticalAlignment]
 ///     The vertical alignment of the Text
+/// max_lines : Optional[int]
+///     The maximum number of lines to display.
+/// wrap : Optional[str]
+///     How to break lines that are wider than the text boundaries:
+///
+///     * "word": at word boundaries (the default),
+///     * "char": at any character, or
+///     * "none": do not break lines at all.
+/// ellipsis : Optional[str]
+///     Where to cut lines that don't fit and put an ellipsis ("…"): "end", "middle" or "start".
+///     If "max_lines" is exceeded, the last displayed line is cut.
+///     Without an ellipsis, overlong lines overflow the boundaries.
+/// tooltip : Optional[TooltipPosition]
+///     If not None, the full text is displayed in a tooltip at the given position.
 ///
 /// Returns
 /// -------
 /// Element
 ///     The newly created text label.
 ///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     text(path, width=Length.FILL, wrap='none', ellipsis='middle', tooltip=TooltipPosition.BOTTOM)
+///
 /// See also
 /// --------
 /// `iced_native::widget::text::Text <https://docs.rs/iced_native/0.4.0/iced_native/widget/text/struct.Text.html>`_
@@ -70,7 +123,39 @@ fn make_text(
     height: Option<&WrappedLength>,
     horizontal_alignment: Option<&WrappedHorizontalAlignment>,
     vertical_alignment: Option<&WrappedVerticalAlignment>,
-) -> WrappedWidgetBuilder {
+    max_lines: Option<usize>,
+    wrap: Option<&str>,
+    ellipsis: Option<&str>,
+    tooltip: Option<&WrappedTooltipPosition>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let wrap = match wrap {
+        None => None,
+        Some("word") => Some(TextWrap::Word),
+        Some("char") => Some(TextWrap::Char),
+        Some("none") => Some(TextWrap::None),
+        Some(_) => {
+            return Err(PyErr::new::<PyValueError, _>(
+                "Expected 'wrap' to be one of \"word\", \"char\" or \"none\"",
+            ));
+        },
+    };
+    let ellipsis = match ellipsis {
+        None => None,
+        Some("start") => Some(Ellipsis::Start),
+        Some("middle") => Some(Ellipsis::Middle),
+        Some("end") => Some(Ellipsis::End),
+        Some(_) => {
+            return Err(PyErr::new::<PyValueError, _>(
+                "Expected 'ellipsis' to be one of \"end\", \"middle\" or \"start\"",
+            ));
+        },
+    };
+    if max_lines == Some(0) {
+        return Err(PyErr::new::<PyValueError, _>(
+            "'max_lines' must be at least 1",
+        ));
+    }
+
     let el = TextBuilder {
         label,
         size,
@@ -80,12 +165,50 @@ fn make_text(
         height: height.map(|o| o.0),
         horizontal_alignment: horizontal_alignment.map(|o| o.0),
         vertical_alignment: vertical_alignment.map(|o| o.0),
+        max_lines,
+        wrap,
+        ellipsis,
     };
-    el.into()
+    Ok(match tooltip {
+        Some(&WrappedTooltipPosition(position)) => with_tooltip(el, position).into(),
+        None => el.into(),
+    })
+}
+
+fn with_tooltip(el: TextBuilder, position: Position) -> TooltipBuilder {
+    TooltipBuilder {
+        tooltip: el.label.clone(),
+        position,
+        size: el.size,
+        font: el.font,
+        gap: None,
+        padding: None,
+        style: None,
+        content: Box::new(el.into()),
+    }
 }
 
 impl ToNative for TextBuilder {
     fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        if self.max_lines.is_some() || self.wrap.is_some() || self.ellipsis.is_some() {
+            return Element::new(Truncated {
+                content: self.label.clone(),
+                size: self.size,
+                color: self.color,
+                font: self.font.unwrap_or_default(),
+                width: self.width.unwrap_or(Length::Shrink),
+                height: self.height.unwrap_or(Length::Shrink),
+                horizontal_alignment: self
+                    .horizontal_alignment
+                    .unwrap_or(HorizontalAlignment::Left),
+                vertical_alignment: self.vertical_alignment.unwrap_or(VerticalAlignment::Top),
+                max_lines: self.max_lines.unwrap_or(usize::MAX),
+                wrap: self.wrap.unwrap_or(TextWrap::Word),
+                ellipsis: self.ellipsis,
+                cache: RefCell::new(None),
+            });
+        }
+
         let el = Text::new(&self.label);
         let el = assign!(
             el,
@@ -101,3 +224,271 @@ impl ToNative for TextBuilder {
         el.into()
     }
 }
+
+/// A text that breaks and cuts its lines itself, so that the ellipsis can be placed exactly.
+struct Truncated {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Font,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    max_lines: usize,
+    wrap: TextWrap,
+    ellipsis: Option<Ellipsis>,
+    /// The result of the last call to [`Truncated::text()`].
+    cache: RefCell<Option<BrokenText>>,
+}
+
+#[derive(Debug, Clone)]
+struct BrokenText {
+    max_width: f32,
+    content: String,
+    width: f32,
+    height: f32,
+}
+
+const ELLIPSIS: &str = "\u{2026}";
+
+impl Truncated {
+    fn size(&self, renderer: &Renderer) -> u16 {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// Returns the broken and cut content, and its size.
+    fn text(&self, renderer: &Renderer, max_width: f32) -> BrokenText {
+        let mut cache = self.cache.borrow_mut();
+        if let Some(text) = &*cache {
+            // The lines are filled greedily, so any width between the widest line and the
+            // old maximum would result in the same lines.
+            if text.max_width == max_width
+                || (text.width <= max_width && max_width <= text.max_width)
+            {
+                return text.clone();
+            }
+        }
+
+        let content = self.lines(renderer, max_width).join("\n");
+        let (width, height) =
+            renderer.measure(&content, self.size(renderer), self.font, Size::INFINITY);
+        let text = BrokenText {
+            max_width,
+            content,
+            width,
+            height,
+        };
+        *cache = Some(text.clone());
+        text
+    }
+
+    /// Breaks and cuts the content, so that every line fits into `max_width` if possible.
+    fn lines(&self, renderer: &Renderer, max_width: f32) -> Vec<String> {
+        let size = self.size(renderer);
+        let measure = |s: &str| renderer.measure(s, size, self.font, Size::INFINITY).0;
+
+        let mut lines = Vec::new();
+        for paragraph in self.content.split('\n') {
+            let paragraph = paragraph.trim_end_matches('\r');
+            match self.wrap {
+                TextWrap::None => lines.push(paragraph.to_owned()),
+                TextWrap::Char => break_graphemes(paragraph, max_width, &measure, &mut lines),
+                TextWrap::Word => break_words(paragraph, max_width, &measure, &mut lines),
+            }
+        }
+
+        if lines.len() > self.max_lines {
+            match self.ellipsis {
+                Some(_) => {
+                    let rest = lines.split_off(self.max_lines - 1);
+                    lines.push(rest.join(" "));
+                },
+                None => lines.truncate(self.max_lines),
+            }
+        }
+
+        if let Some(ellipsis) = self.ellipsis {
+            for line in lines.iter_mut() {
+                if measure(line) > max_width {
+                    *line = cut(line, ellipsis, max_width, &measure);
+                }
+            }
+        }
+        lines
+    }
+}
+
+/// Greedily fills lines with words; words that are too long on their own are broken at any
+/// character. Only the words are measured, not the whole lines.
+fn break_words(
+    paragraph: &str,
+    max_width: f32,
+    measure: &dyn Fn(&str) -> f32,
+    lines: &mut Vec<String>,
+) {
+    let mut line = String::new();
+    let mut line_width = 0.0;
+    // whitespace after the last word of the line, which is not measured on its own
+    let mut gap = String::new();
+    for word in paragraph.split_word_bounds() {
+        if word.trim().is_empty() {
+            gap.push_str(word);
+            continue;
+        }
+
+        let width = measure(&[&gap, word].concat());
+        if line.is_empty() || line_width + width <= max_width {
+            line.push_str(&gap);
+            line.push_str(word);
+            line_width += width;
+        } else {
+            lines.push(std::mem::take(&mut line));
+            line.push_str(word);
+            line_width = measure(word);
+        }
+        gap.clear();
+
+        if line_width > max_width {
+            let mut pieces = Vec::new();
+            break_graphemes(&line, max_width, measure, &mut pieces);
+            line = pieces.pop().unwrap_or_default();
+            line_width = measure(&line);
+            lines.extend(pieces);
+        }
+    }
+    lines.push(line);
+}
+
+/// Greedily fills lines with characters. Each line contains at least one character.
+fn break_graphemes(
+    paragraph: &str,
+    max_width: f32,
+    measure: &dyn Fn(&str) -> f32,
+    lines: &mut Vec<String>,
+) {
+    let graphemes: Vec<&str> = paragraph.graphemes(true).collect();
+    if graphemes.is_empty() {
+        lines.push(String::new());
+        return;
+    }
+
+    let mut start = 0;
+    while start < graphemes.len() {
+        // binary search for the greatest number of characters that still fits
+        let (mut low, mut high) = (start + 1, graphemes.len());
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if measure(&graphemes[start..mid].concat()) <= max_width {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        lines.push(graphemes[start..low].concat());
+        start = low;
+    }
+}
+
+/// Replaces as few characters as possible with an ellipsis, so that the line fits into
+/// `max_width`.
+fn cut(line: &str, ellipsis: Ellipsis, max_width: f32, measure: &dyn Fn(&str) -> f32) -> String {
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+    let compose = |kept: usize| -> String {
+        let (head, tail) = match ellipsis {
+            Ellipsis::End => (kept, 0),
+            Ellipsis::Start => (0, kept),
+            Ellipsis::Middle => (kept.div_ceil(2), kept / 2),
+        };
+        let head = graphemes[..head].concat();
+        let tail = graphemes[graphemes.len() - tail..].concat();
+        [head.trim_end(), ELLIPSIS, tail.trim_start()].concat()
+    };
+
+    // binary search for the greatest number of kept characters that still fits
+    let (mut low, mut high) = (0, graphemes.len());
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if measure(&compose(mid)) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    compose(low)
+}
+
+impl Widget<Message, Renderer> for Truncated {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let text = self.text(renderer, limits.max().width);
+        Node::new(limits.resolve(Size::new(text.width, text.height)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let bounds = layout.bounds();
+        let size = self.size(renderer);
+        let BrokenText { content, width, .. } = self.text(renderer, bounds.width);
+
+        // The lines are already broken, so the renderer must not break them again.
+        let width = bounds.width.max(width);
+        let x = match self.horizontal_alignment {
+            HorizontalAlignment::Left => bounds.x,
+            HorizontalAlignment::Center => bounds.center_x(),
+            HorizontalAlignment::Right => bounds.x + bounds.width,
+        };
+        let y = match self.vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => bounds.center_y(),
+            VerticalAlignment::Bottom => bounds.y + bounds.height,
+        };
+
+        let primitive = Primitive::Text {
+            content,
+            size: size as f32,
+            bounds: Rectangle {
+                x,
+                y,
+                width,
+                height: bounds.height,
+            },
+            color: self.color.unwrap_or(defaults.text.color),
+            font: self.font,
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+        };
+        (primitive, mouse::Interaction::default())
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_lines.hash(state);
+        self.wrap.hash(state);
+        self.ellipsis.hash(state);
+        match self.font {
+            Font::Default => 0u8.hash(state),
+            Font::External { name, .. } => name.hash(state),
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::text_input::StyleSheet;
+use iced::{Element, Font, HorizontalAlignment, Length, Point, Rectangle, Size, VerticalAlignment};
+use iced_native::keyboard::KeyCode;
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{event, keyboard, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTime, PyTimeAccess};
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::states::{TimePickerState, TimeState, WrappedTimePickerState};
+use crate::styles::{TextInputStyleSheet, WrappedTextInputStyleSheet};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedLength};
+use crate::{format_to_string_ignore, make_with_state};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_time_picker, m)?)?;
+    Ok(())
+}
+
+const FIELD_NAMES: [&str; 3] = ["hour", "minute", "second"];
+const FIELD_MAX: [u8; 3] = [23, 59, 59];
+
+#[derive(Debug, Clone)]
+pub(crate) struct TimePickerBuilder {
+    pub state: TimePickerState,
+    pub token: Py<PyAny>,
+    pub time: Option<Py<PyAny>>,
+    pub values: [u8; 3],
+    pub show_seconds: bool,
+    pub width: Length,
+    pub padding: u16,
+    pub text_size: Option<u16>,
+    pub font: Font,
+    pub style: Option<TextInputStyleSheet>,
+}
+
+impl GCProtocol for TimePickerBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        if let Some(time) = &self.time {
+            visit.call(time)?;
+        }
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "time_picker")]
+/// time_picker($module, /, token, state, time, *, show_seconds=False, width=None, padding=None, text_size=None, font=None, style=None)
+/// --
+///
+/// A field to enter a time of the day.
+///
+/// The hours, minutes and seconds are separate fields. The field under the mouse cursor can be changed
+/// with the mouse wheel. A clicked field can be changed with the up and down keys, or by typing a number.
+/// The left and right keys move to the previous or next field.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user changes the time, a message ``(token, new_time)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+///     ``new_time`` is ``time`` with the changed field :meth:`replaced <datetime.time.replace()>`,
+///     so its microseconds and timezone are kept.
+/// state : TimePickerState
+///     Current state of the time picker. The same object must be given between calls.
+/// time : Optional[datetime.time]
+///     The current time. If None, then the fields are empty, and midnight is used as the initial value.
+/// show_seconds : bool
+///     Whether to show a field for the seconds.
+/// width : Optional[Length]
+///     The width of the time picker. Defaults to Length.SHRINK.
+/// padding : Optional[int]
+///     The padding of the text. Defaults to 5.
+/// text_size : Optional[int]
+///     The text size.
+/// font : Optional[Font]
+///     The font of the text.
+/// style : Optional[TextInputStyleSheet]
+///     The style of the time picker. The focused field is highlighted with its "selection_color".
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created time picker.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return time_picker('alarm', self.__alarm_state, self.__alarm)
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('alarm', value):
+///                 self.__alarm = value
+///
+/// See also
+/// --------
+/// :func:`~pyiced.date_picker()`
+fn make_time_picker(
+    py: Python,
+    token: Py<PyAny>,
+    state: &WrappedTimePickerState,
+    time: Option<&PyTime>,
+    show_seconds: Option<bool>,
+    width: Option<&WrappedLength>,
+    padding: Option<u16>,
+    text_size: Option<u16>,
+    font: Option<&WrappedFont>,
+    style: Option<&WrappedTextInputStyleSheet>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let values = match time {
+        Some(time) => [time.get_hour(), time.get_minute(), time.get_second()],
+        None => [0, 0, 0],
+    };
+    let el = TimePickerBuilder {
+        state: state.0.clone(),
+        token,
+        time: time.map(|time| time.into_py(py)),
+        values,
+        show_seconds: show_seconds.unwrap_or(false),
+        width: width.map_or(Length::Shrink, |o| o.0),
+        padding: padding.unwrap_or(5),
+        text_size,
+        font: font.map_or(Font::Default, |o| o.0),
+        style: style.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for TimePickerBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        time_picker_with_state(&self.state, |state| {
+            Ok(TimePicker {
+                state,
+                builder: self.clone(),
+            })
+        })
+    }
+}
+
+make_with_state! {
+    time_picker_with_state(
+        crate::widgets::time_picker::TimePicker,
+        crate::widgets::time_picker::TimePicker<'this>,
+        crate::states::TimeState,
+    );
+}
+
+pub(crate) struct TimePicker<'a> {
+    state: &'a mut TimeState,
+    builder: TimePickerBuilder,
+}
+
+impl TimePicker<'_> {
+    fn field_count(&self) -> usize {
+        match self.builder.show_seconds {
+            true => 3,
+            false => 2,
+        }
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> u16 {
+        self.builder
+            .text_size
+            .unwrap_or_else(|| renderer.default_size())
+    }
+
+    /// The width of a field and of a separator.
+    fn measure(&self, renderer: &Renderer) -> (f32, f32) {
+        let text_size = self.text_size(renderer);
+        let measure = |text| {
+            let (width, _) = renderer.measure(
+                text,
+                text_size,
+                self.builder.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            );
+            width.ceil()
+        };
+        let gap = (text_size / 4) as f32;
+        (measure("00") + 2.0 * gap, measure(":"))
+    }
+
+    fn fields(&self, renderer: &Renderer, bounds: Rectangle) -> Vec<Rectangle> {
+        let (field_width, separator_width) = self.measure(renderer);
+        let padding = self.builder.padding as f32;
+        (0..self.field_count())
+            .map(|index| Rectangle {
+                x: bounds.x + padding + index as f32 * (field_width + separator_width),
+                y: bounds.y + padding,
+                width: field_width,
+                height: bounds.height - 2.0 * padding,
+            })
+            .collect()
+    }
+
+    /// The field under the cursor, or the nearest field in the same line.
+    fn field_at(&self, renderer: &Renderer, bounds: Rectangle, point: Point) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+        self.fields(renderer, bounds)
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                let a = (a.center_x() - point.x).abs();
+                let b = (b.center_x() - point.x).abs();
+                a.total_cmp(&b)
+            })
+            .map(|(index, _)| index)
+    }
+
+    fn emit(&self, values: [u8; 3], messages: &mut Vec<Message>) {
+        if self.builder.time.is_some() && values == self.builder.values {
+            return;
+        }
+        let [hour, minute, second] = values;
+        let message = Python::with_gil(|py| {
+            let time = match &self.builder.time {
+                Some(time) => {
+                    let kwargs = PyDict::new(py);
+                    for (name, value) in FIELD_NAMES.iter().zip(values) {
+                        kwargs.set_item(name, value)?;
+                    }
+                    time.call_method(py, "replace", (), Some(kwargs))?
+                },
+                None => PyTime::new(py, hour, minute, second, 0, None)?.into_py(py),
+            };
+            PyResult::Ok((&self.builder.token, time).into_py(py))
+        });
+        match message {
+            Ok(message) => messages.push(Message::Python(message)),
+            Err(err) => Python::with_gil(|py| err.print(py)),
+        }
+    }
+
+    fn step(&self, field: usize, steps: i32, messages: &mut Vec<Message>) {
+        let mut values = self.builder.values;
+        let modulo = FIELD_MAX[field] as i32 + 1;
+        values[field] = (values[field] as i32 + steps).rem_euclid(modulo) as u8;
+        self.emit(values, messages);
+    }
+
+    fn focus(&mut self, field: Option<usize>) {
+        self.state.focused = field;
+        self.state.typed = None;
+    }
+
+    fn type_digit(&mut self, field: usize, digit: u8, messages: &mut Vec<Message>) {
+        let max = FIELD_MAX[field];
+        let (value, is_complete) = match self.state.typed.take() {
+            Some(first) if first * 10 + digit <= max => (first * 10 + digit, true),
+            _ => (digit, digit * 10 > max),
+        };
+        let mut values = self.builder.values;
+        values[field] = value;
+        self.emit(values, messages);
+
+        match is_complete {
+            true if field + 1 < self.field_count() => self.focus(Some(field + 1)),
+            true => {},
+            false => self.state.typed = Some(digit),
+        }
+    }
+}
+
+impl Widget<Message, Renderer> for TimePicker<'_> {
+    fn width(&self) -> Length {
+        self.builder.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let padding = self.builder.padding as f32;
+        let (field_width, separator_width) = self.measure(renderer);
+        let count = self.field_count() as f32;
+        let intrinsic = Size::new(
+            count * field_width + (count - 1.0) * separator_width,
+            self.text_size(renderer) as f32,
+        );
+        let limits = limits
+            .width(self.builder.width)
+            .height(Length::Shrink)
+            .pad(padding);
+        Node::new(limits.resolve(intrinsic).pad(padding))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let style_sheet: Box<dyn StyleSheet> = match self.builder.style {
+            Some(style) => Box::new(style),
+            None => Default::default(),
+        };
+        let bounds = layout.bounds();
+        let is_hovered = bounds.contains(cursor_position);
+        let style = match (self.state.focused, is_hovered) {
+            (Some(_), _) => style_sheet.focused(),
+            (None, true) => style_sheet.hovered(),
+            (None, false) => style_sheet.active(),
+        };
+        let color = match self.builder.time {
+            Some(_) => style_sheet.value_color(),
+            None => style_sheet.placeholder_color(),
+        };
+
+        let text_size = self.text_size(renderer) as f32;
+        let text = |content: String, x: f32, y: f32| Primitive::Text {
+            content,
+            bounds: Rectangle {
+                x,
+                y,
+                width: bounds.width,
+                height: text_size,
+            },
+            color,
+            size: text_size,
+            font: self.builder.font,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        };
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }];
+        let fields = self.fields(renderer, bounds);
+        for (index, field) in fields.iter().enumerate() {
+            if self.state.focused == Some(index) {
+                primitives.push(Primitive::Quad {
+                    bounds: *field,
+                    background: style_sheet.selection_color().into(),
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: style.border_color,
+                });
+            }
+            let content = match self.builder.time {
+                Some(_) => {
+                    format_to_string_ignore!("{:02}", self.builder.values[index]).into_owned()
+                },
+                None => "--".to_owned(),
+            };
+            primitives.push(text(content, field.center_x(), field.center_y()));
+            if let Some(next) = fields.get(index + 1) {
+                let x = (field.x + field.width + next.x) / 2.0;
+                primitives.push(text(":".to_owned(), x, field.center_y()));
+            }
+        }
+
+        let interaction = match is_hovered {
+            true => mouse::Interaction::Text,
+            false => mouse::Interaction::default(),
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.builder.show_seconds.hash(state);
+        self.builder.width.hash(state);
+        self.builder.padding.hash(state);
+        self.builder.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        match (event, self.state.focused) {
+            (Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)), _) => {
+                let field = self.field_at(renderer, bounds, cursor_position);
+                self.focus(field);
+                match field {
+                    Some(_) => event::Status::Captured,
+                    None => event::Status::Ignored,
+                }
+            },
+            (Event::Mouse(mouse::Event::WheelScrolled { delta }), _) => {
+                let field = match self.field_at(renderer, bounds, cursor_position) {
+                    Some(field) => field,
+                    None => return event::Status::Ignored,
+                };
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                if y != 0.0 {
+                    self.state.typed = None;
+                    self.step(field, y.signum() as i32, messages);
+                }
+                event::Status::Captured
+            },
+            (Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }), Some(field)) => {
+                match key_code {
+                    KeyCode::Up => self.step(field, 1, messages),
+                    KeyCode::Down => self.step(field, -1, messages),
+                    KeyCode::Left if field > 0 => self.focus(Some(field - 1)),
+                    KeyCode::Right if field + 1 < self.field_count() => self.focus(Some(field + 1)),
+                    KeyCode::Escape => self.focus(None),
+                    KeyCode::Backspace => self.state.typed = None,
+                    _ => return event::Status::Ignored,
+                }
+                event::Status::Captured
+            },
+            (Event::Keyboard(keyboard::Event::CharacterReceived(c)), Some(field)) => {
+                match c.to_digit(10) {
+                    Some(digit) => {
+                        self.type_digit(field, digit as u8, messages);
+                        event::Status::Captured
+                    },
+                    None => event::Status::Ignored,
+                }
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+}
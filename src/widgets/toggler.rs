@@ -0,0 +1,275 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::{Align, Element, Font, HorizontalAlignment, Length, Point, Rectangle, Row, Space, Text};
+use iced_native::layout::{Limits, Node};
+use iced_native::widget::text::Renderer as _;
+use iced_native::{event, mouse, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::styles::{TogglerStyleSheet, WrappedTogglerStyleSheet};
+use crate::widgets::WrappedWidgetBuilder;
+use crate::wrapped::{WrappedFont, WrappedHorizontalAlignment, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_toggler, m)?)?;
+    Ok(())
+}
+
+const DEFAULT_SIZE: u16 = 20;
+const BORDER_RADIUS_RATIO: f32 = 32.0 / 13.0;
+const SPACE_RATIO: f32 = 0.05;
+
+#[derive(Debug, Clone)]
+pub(crate) struct TogglerBuilder {
+    pub is_active: bool,
+    pub token: Py<PyAny>,
+    pub label: Option<String>,
+    pub size: u16,
+    pub width: Length,
+    pub spacing: u16,
+    pub text_size: Option<u16>,
+    pub text_alignment: HorizontalAlignment,
+    pub font: Font,
+    pub style: TogglerStyleSheet,
+}
+
+impl GCProtocol for TogglerBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.token)?;
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "toggler")]
+/// toggler($module, /, token, is_active, label=None, *, size=None, width=None, spacing=None, text_size=None, text_alignment=None, font=None, style=None)
+/// --
+///
+/// A switch that can be toggled on and off.
+///
+/// Parameters
+/// ----------
+/// token : object
+///     When the user toggles the switch, a message ``(token, new_is_active)`` is sent to the app's :meth:`~pyiced.IcedApp.update()` method.
+/// is_active : bool
+///     Whether the toggler is currently switched on or not.
+/// label : Optional[str]
+///     A text besides the switch.
+/// size : Optional[int]
+///     Height of the switch. Its width is twice the height. Defaults to 20.
+/// width : Optional[Length]
+///     Width of the widget (text and switch). Defaults to Length.FILL.
+/// spacing : Optional[int]
+///     Space between text and switch.
+/// text_size : Optional[int]
+///     Font size of the text.
+/// text_alignment : Optional[HorizontalAlignment]
+///     Alignment of the text. Defaults to HorizontalAlignment.LEFT.
+/// font : Optional[Font]
+///     Font of the text.
+/// style : Optional[TogglerStyleSheet]
+///     Style of the switch.
+///
+/// Returns
+/// -------
+/// Element
+///     Newly created toggler.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def view(self):
+///         return toggler('dark_mode', self.__dark_mode, 'Dark mode')
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('dark_mode', value):
+///                 self.__dark_mode = value
+///
+/// See also
+/// --------
+/// :func:`~pyiced.checkbox()`
+fn make_toggler(
+    token: Py<PyAny>,
+    is_active: bool,
+    label: Option<String>,
+    size: Option<u16>,
+    width: Option<&WrappedLength>,
+    spacing: Option<u16>,
+    text_size: Option<u16>,
+    text_alignment: Option<&WrappedHorizontalAlignment>,
+    font: Option<&WrappedFont>,
+    style: Option<&WrappedTogglerStyleSheet>,
+) -> WrappedWidgetBuilder {
+    let el = TogglerBuilder {
+        is_active,
+        token,
+        label,
+        size: size.unwrap_or(DEFAULT_SIZE),
+        width: width.map_or(Length::Fill, |o| o.0),
+        spacing: spacing.unwrap_or(0),
+        text_size,
+        text_alignment: text_alignment.map_or(HorizontalAlignment::Left, |o| o.0),
+        font: font.map_or(Font::Default, |o| o.0),
+        style: style.map(|o| o.0).unwrap_or_default(),
+    };
+    el.into()
+}
+
+impl ToNative for TogglerBuilder {
+    fn to_native(&self, _py: Python) -> Element<'static, Message> {
+        Element::new(Toggler(self.clone()))
+    }
+}
+
+struct Toggler(TogglerBuilder);
+
+impl Toggler {
+    /// The label (if any) and the switch, laid out in a row.
+    fn row(&self, renderer: &Renderer) -> Row<'static, Message> {
+        let mut row = Row::new()
+            .width(self.0.width)
+            .spacing(self.0.spacing)
+            .align_items(Align::Center);
+        if let Some(label) = &self.0.label {
+            row = row.push(self.text(renderer, label));
+        }
+        row.push(Space::new(
+            Length::Units(2 * self.0.size),
+            Length::Units(self.0.size),
+        ))
+    }
+
+    fn text(&self, renderer: &Renderer, label: &str) -> Text {
+        Text::new(label)
+            .horizontal_alignment(self.0.text_alignment)
+            .font(self.0.font)
+            .width(self.0.width)
+            .size(self.0.text_size.unwrap_or_else(|| renderer.default_size()))
+    }
+}
+
+impl Widget<Message, Renderer> for Toggler {
+    fn width(&self) -> Length {
+        self.0.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        Widget::layout(&self.row(renderer), renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let mut children = layout.children();
+        let mut primitives = Vec::with_capacity(3);
+        if let Some(label) = &self.0.label {
+            if let Some(text_layout) = children.next() {
+                let text = self.text(renderer, label);
+                let (primitive, _) = Widget::<Message, Renderer>::draw(
+                    &text,
+                    renderer,
+                    defaults,
+                    text_layout,
+                    cursor_position,
+                    viewport,
+                );
+                primitives.push(primitive);
+            }
+        }
+
+        let is_hovered = layout.bounds().contains(cursor_position);
+        if let Some(switch_layout) = children.next() {
+            let bounds = switch_layout.bounds();
+            let style = self.0.style.style(self.0.is_active, is_hovered);
+            let border_radius = bounds.height / BORDER_RADIUS_RATIO;
+            let space = SPACE_RATIO * bounds.height;
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + space,
+                    y: bounds.y + space,
+                    width: bounds.width - 2.0 * space,
+                    height: bounds.height - 2.0 * space,
+                },
+                background: style.background.into(),
+                border_radius,
+                border_width: 1.0,
+                border_color: style.background_border.unwrap_or(style.background),
+            });
+
+            let knob = bounds.height - 4.0 * space;
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: match self.0.is_active {
+                        true => bounds.x + bounds.width - 2.0 * space - knob,
+                        false => bounds.x + 2.0 * space,
+                    },
+                    y: bounds.y + 2.0 * space,
+                    width: knob,
+                    height: knob,
+                },
+                background: style.foreground.into(),
+                border_radius,
+                border_width: 1.0,
+                border_color: style.foreground_border.unwrap_or(style.foreground),
+            });
+        }
+
+        let interaction = match is_hovered {
+            true => mouse::Interaction::Pointer,
+            false => mouse::Interaction::default(),
+        };
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.0.label.hash(state);
+        self.0.size.hash(state);
+        self.0.width.hash(state);
+        self.0.spacing.hash(state);
+        self.0.text_size.hash(state);
+        match self.0.font {
+            Font::Default => 0u8.hash(state),
+            Font::External { name, .. } => name.hash(state),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let message = Python::with_gil(|py| (&self.0.token, !self.0.is_active).into_py(py));
+                messages.push(Message::Python(message));
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+}
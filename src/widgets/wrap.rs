@@ -0,0 +1,268 @@
+use std::any::TypeId;
+use std::hash::Hash;
+
+use iced::{Align, Element, Length, Point, Rectangle, Size};
+use iced_native::layout::{Limits, Node};
+use iced_native::{event, mouse, overlay, Clipboard, Event, Hasher, Layout, Widget};
+#[cfg(feature = "wgpu")]
+use iced_wgpu::{Defaults, Primitive, Renderer};
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::common::{GCProtocol, Message, ToNative};
+use crate::widgets::{WidgetBuilder, WrappedWidgetBuilder};
+use crate::wrapped::{WrappedAlign, WrappedLength};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(make_wrap, m)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WrapBuilder {
+    pub children: Vec<WidgetBuilder>,
+    pub spacing: Option<u16>,
+    pub line_spacing: Option<u16>,
+    pub padding: Option<u16>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub align_items: Option<Align>,
+}
+
+impl GCProtocol for WrapBuilder {
+    fn traverse(&self, visit: &pyo3::PyVisit) -> Result<(), pyo3::PyTraverseError> {
+        for child in self.children.iter() {
+            child.traverse(visit)?;
+        }
+        Ok(())
+    }
+}
+
+#[pyfunction(name = "wrap")]
+/// wrap($module, /, children, *, spacing=None, line_spacing=None, padding=None, width=None, height=None, align_items=None)
+/// --
+///
+/// A container that distributes its contents horizontally, and starts a new line if the
+/// available width is exhausted.
+///
+/// Parameters
+/// ----------
+/// children : Iterable[Optional[Element]]
+///     Create the wrap with the given elements.
+/// spacing : Optional[int]
+///     Horizontal spacing between the elements of a line.
+/// line_spacing : Optional[int]
+///     Vertical spacing between the lines. Defaults to "spacing".
+/// padding : Optional[int]
+///     Padding of the wrap.
+/// width : Optional[Length]
+///     Width of the wrap.
+/// height : Optional[Length]
+///     Height of the wrap.
+/// align_items : Optional[Align]
+///     Vertical alignment of the elements in their line.
+///
+/// Returns
+/// -------
+/// Element
+///     The newly created wrap.
+///
+/// See also
+/// --------
+/// :func:`~pyiced.row()`
+fn make_wrap(
+    py: Python,
+    children: &PyAny,
+    spacing: Option<u16>,
+    line_spacing: Option<u16>,
+    padding: Option<u16>,
+    width: Option<&WrappedLength>,
+    height: Option<&WrappedLength>,
+    align_items: Option<&WrappedAlign>,
+) -> PyResult<WrappedWidgetBuilder> {
+    let children = children
+        .iter()?
+        .filter_map(|child| match child {
+            Ok(child) => match child.is_none() {
+                false => match child.extract() {
+                    Ok(WrappedWidgetBuilder(child)) => Some(child),
+                    Err(err) => {
+                        err.print(py);
+                        None
+                    },
+                },
+                true => None,
+            },
+            Err(err) => {
+                err.print(py);
+                None
+            },
+        })
+        .collect();
+    let el = WrapBuilder {
+        children,
+        spacing,
+        line_spacing,
+        padding,
+        width: width.map(|o| o.0),
+        height: height.map(|o| o.0),
+        align_items: align_items.map(|o| o.0),
+    };
+    Ok(el.into())
+}
+
+impl ToNative for WrapBuilder {
+    fn to_native(&self, py: Python) -> Element<'static, Message> {
+        let spacing = self.spacing.unwrap_or(0);
+        Element::new(Wrap {
+            children: self.children.iter().map(|c| c.to_native(py)).collect(),
+            spacing: spacing as f32,
+            line_spacing: self.line_spacing.unwrap_or(spacing) as f32,
+            padding: self.padding.unwrap_or(0) as f32,
+            width: self.width.unwrap_or(Length::Shrink),
+            height: self.height.unwrap_or(Length::Shrink),
+            align_items: self.align_items.unwrap_or(Align::Start),
+        })
+    }
+}
+
+struct Wrap {
+    children: Vec<Element<'static, Message>>,
+    spacing: f32,
+    line_spacing: f32,
+    padding: f32,
+    width: Length,
+    height: Length,
+    align_items: Align,
+}
+
+impl Wrap {
+    fn align_line(&self, nodes: &mut [Node], line_height: f32) {
+        for node in nodes.iter_mut() {
+            let bounds = node.bounds();
+            let dy = match self.align_items {
+                Align::Start => 0.0,
+                Align::Center => (line_height - bounds.height) / 2.0,
+                Align::End => line_height - bounds.height,
+            };
+            node.move_to(Point::new(bounds.x, bounds.y + dy));
+        }
+    }
+}
+
+impl Widget<Message, Renderer> for Wrap {
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+        let max_width = limits.max().width;
+        let child_limits = limits.loose();
+
+        let mut nodes: Vec<Node> = Vec::with_capacity(self.children.len());
+        let mut line_start = 0;
+        let mut line_height = 0.0f32;
+        let mut content_width = 0.0f32;
+        let (mut x, mut y) = (0.0f32, 0.0f32);
+        for child in self.children.iter() {
+            let mut node = child.layout(renderer, &child_limits);
+            let size = node.size();
+            if line_start < nodes.len() && x + size.width > max_width {
+                self.align_line(&mut nodes[line_start..], line_height);
+                line_start = nodes.len();
+                x = 0.0;
+                y += line_height + self.line_spacing;
+                line_height = 0.0;
+            }
+            node.move_to(Point::new(x + self.padding, y + self.padding));
+            nodes.push(node);
+
+            content_width = content_width.max(x + size.width);
+            line_height = line_height.max(size.height);
+            x += size.width + self.spacing;
+        }
+        self.align_line(&mut nodes[line_start..], line_height);
+
+        let content_size = Size::new(content_width, y + line_height);
+        let size = limits.resolve(content_size).pad(self.padding);
+        Node::with_children(size, nodes)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let mut interaction = mouse::Interaction::default();
+        let primitives = self
+            .children
+            .iter()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                let (primitive, child_interaction) =
+                    child.draw(renderer, defaults, layout, cursor_position, viewport);
+                interaction = interaction.max(child_interaction);
+                primitive
+            })
+            .collect();
+        (Primitive::Group { primitives }, interaction)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.spacing.to_bits().hash(state);
+        self.line_spacing.to_bits().hash(state);
+        self.padding.to_bits().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.align_items.hash(state);
+        for child in self.children.iter() {
+            child.hash_layout(state);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    messages,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.overlay(layout))
+    }
+}
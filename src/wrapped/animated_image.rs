@@ -0,0 +1,261 @@
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use iced::image::Handle;
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, Frame, ImageFormat, ImageResult};
+use parking_lot::Mutex;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::format_to_string_ignore;
+use crate::wrapped::PixelFormat;
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedAnimatedImageHandle>()?;
+    Ok(())
+}
+
+/// An :func:`pyiced.animated_image` handle.
+///
+/// All frames of the animation are decoded once, when the handle is created.
+///
+/// The handle keeps track of the playback position, so the animation continues where it was,
+/// even if the element is rebuilt. If the same handle is used in multiple elements, then they
+/// share the playback position.
+///
+/// Animated GIF, APNG and WebP files are supported. Other images are shown as a single frame.
+#[pyclass(name = "AnimatedImageHandle", module = "pyiced")]
+#[derive(Debug, Clone)]
+pub(crate) struct WrappedAnimatedImageHandle(pub Arc<AnimatedImage>);
+
+#[derive(Debug)]
+pub(crate) struct AnimatedImage {
+    pub frames: Vec<(Handle, Duration)>,
+    pub duration: Duration,
+    pub width: u32,
+    pub height: u32,
+    pub playback: Mutex<Playback>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Playback {
+    /// Time played before the animation was last resumed.
+    pub offset: Duration,
+    /// When the animation was last resumed, or None if it is paused.
+    pub resumed: Option<Instant>,
+    /// A non-looping animation was played until its end.
+    pub finished: bool,
+}
+
+impl Playback {
+    pub(crate) fn elapsed(&self, now: Instant) -> Duration {
+        let since = self
+            .resumed
+            .map_or(Duration::ZERO, |r| now.saturating_duration_since(r));
+        self.offset + since
+    }
+}
+
+impl AnimatedImage {
+    fn decode(data: &[u8]) -> ImageResult<Self> {
+        let frames = match image::guess_format(data)? {
+            ImageFormat::Gif => GifDecoder::new(Cursor::new(data))?
+                .into_frames()
+                .collect_frames()?,
+            ImageFormat::Png => match PngDecoder::new(Cursor::new(data))? {
+                decoder if decoder.is_apng() => decoder.apng().into_frames().collect_frames()?,
+                _ => Vec::new(),
+            },
+            ImageFormat::WebP => WebPDecoder::new(Cursor::new(data))?
+                .into_frames()
+                .collect_frames()?,
+            _ => Vec::new(),
+        };
+        let frames = match frames.is_empty() {
+            false => frames,
+            true => vec![Frame::new(image::load_from_memory(data)?.to_rgba8())],
+        };
+
+        let (width, height) = frames[0].buffer().dimensions();
+        let frames: Vec<_> = frames
+            .into_iter()
+            .map(|frame| {
+                // Like web browsers do, treat (almost) zero delays as 100 ms.
+                let delay = match Duration::from(frame.delay()) {
+                    delay if delay < Duration::from_millis(10) => Duration::from_millis(100),
+                    delay => delay,
+                };
+                let buffer = frame.into_buffer();
+                let (width, height) = buffer.dimensions();
+                let pixels = PixelFormat::Rgba8.to_bgra(buffer.into_raw());
+                (Handle::from_pixels(width, height, pixels), delay)
+            })
+            .collect();
+        let duration = match frames.len() {
+            1 => Duration::ZERO,
+            _ => frames.iter().map(|(_, delay)| *delay).sum(),
+        };
+        Ok(Self {
+            frames,
+            duration,
+            width,
+            height,
+            playback: Mutex::default(),
+        })
+    }
+
+    /// Returns the index of the frame to show after `elapsed` time,
+    /// and the time until the next frame is due, or None if the image won't change anymore.
+    pub(crate) fn locate(&self, elapsed: Duration, looping: bool) -> (usize, Option<Duration>) {
+        if self.duration.is_zero() {
+            return (0, None);
+        } else if !looping && elapsed >= self.duration {
+            return (self.frames.len() - 1, None);
+        }
+
+        let position = Duration::from_nanos((elapsed.as_nanos() % self.duration.as_nanos()) as _);
+        let mut end = Duration::ZERO;
+        for (index, (_, delay)) in self.frames.iter().enumerate() {
+            end += *delay;
+            if position < end {
+                return (index, Some(end - position));
+            }
+        }
+        (0, Some(self.frames[0].1))
+    }
+}
+
+fn decode(py: Python, data: &[u8]) -> PyResult<WrappedAnimatedImageHandle> {
+    match py.allow_threads(|| AnimatedImage::decode(data)) {
+        Ok(image) => Ok(WrappedAnimatedImageHandle(Arc::new(image))),
+        Err(err) => {
+            let msg = format_to_string_ignore!("Could not decode image: {}", err);
+            Err(PyErr::new::<PyValueError, _>(msg))
+        },
+    }
+}
+
+#[pymethods]
+impl WrappedAnimatedImageHandle {
+    /// from_path(path)
+    /// --
+    ///
+    /// Decodes all frames of an image file.
+    ///
+    /// Arguments
+    /// ---------
+    /// path : pathlib.Path
+    ///     The path of the image file.
+    ///
+    /// Returns
+    /// -------
+    /// AnimatedImageHandle
+    ///     The new image handle.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The file could not be read or decoded.
+    #[staticmethod]
+    fn from_path(py: Python, path: PathBuf) -> PyResult<Self> {
+        match py.allow_threads(|| std::fs::read(path)) {
+            Ok(data) => decode(py, &data),
+            Err(err) => {
+                let msg = format_to_string_ignore!("Could not open image: {}", err);
+                Err(PyErr::new::<PyValueError, _>(msg))
+            },
+        }
+    }
+
+    /// from_memory(bytes)
+    /// --
+    ///
+    /// Decodes all frames of the data of an image file.
+    ///
+    /// Arguments
+    /// ---------
+    /// bytes : bytes-like
+    ///     The data of the image file, e.g. :class:`bytes`, :class:`bytearray` or :class:`memoryview`.
+    ///
+    /// Returns
+    /// -------
+    /// AnimatedImageHandle
+    ///     The new image handle.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The data could not be decoded.
+    #[staticmethod]
+    fn from_memory(py: Python, bytes: PyBuffer<u8>) -> PyResult<Self> {
+        decode(py, &bytes.to_vec(py)?)
+    }
+
+    /// restart($self)
+    /// --
+    ///
+    /// Rewinds the animation to its first frame.
+    fn restart(&self) {
+        *self.0.playback.lock() = Playback::default();
+    }
+
+    /// The number of frames in the animation.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The number of frames.
+    #[getter]
+    fn frame_count(&self) -> usize {
+        self.0.frames.len()
+    }
+
+    /// The length of one pass through the animation in seconds.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    ///     The length, 0.0 if the image is not animated.
+    #[getter]
+    fn duration(&self) -> f64 {
+        self.0.duration.as_secs_f64()
+    }
+
+    /// The width of the image in pixels.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The width.
+    #[getter]
+    fn width(&self) -> u32 {
+        self.0.width
+    }
+
+    /// The height of the image in pixels.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The height.
+    #[getter]
+    fn height(&self) -> u32 {
+        self.0.height
+    }
+
+    fn __repr__(&self) -> String {
+        format_to_string_ignore!(
+            "<AnimatedImageHandle {}x{}, {} frames>",
+            self.0.width,
+            self.0.height,
+            self.0.frames.len(),
+        )
+        .into_owned()
+    }
+}
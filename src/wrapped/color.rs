@@ -155,3 +155,14 @@ impl Display for ColorFormat<'_> {
         }
     }
 }
+
+/// Converts the color into 8-bit RGBA channels.
+pub(crate) fn color_to_rgba8(color: &Color) -> [u8; 4] {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    [
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        channel(color.a),
+    ]
+}
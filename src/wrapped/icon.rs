@@ -1,27 +1,39 @@
+use std::borrow::Cow;
 use std::path::PathBuf;
 
 use iced::window::Icon;
-use image::io::Reader;
+use iced_native::image::Data;
+use image::{ImageFormat, ImageResult, RgbaImage};
+use pyo3::buffer::PyBuffer;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::common::debug_str;
 use crate::format_to_string_ignore;
+use crate::wrapped::{
+    rasterize_handle, read_pixels, PixelFormat, WrappedImageHandle, WrappedSvgHandle,
+};
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<WrappedIcon>()?;
     Ok(())
 }
 
-/// Icon(path)
+/// Icon(path, *, size=None)
 /// --
 ///
 /// The icon of a window.
 ///
+/// The icon is set when the application starts, see :attr:`~pyiced.WindowSettings.icon`.
+/// iced does not support changing it afterwards.
+///
 /// Parameters
 /// ----------
 /// path : Path
-///     Path to load the icon from. Should be a PNG or BMP file.
+///     Path to load the icon from. Should be a PNG, ICO or BMP file.
+/// size : Optional[int]
+///     If the file is an ICO file with multiple entries, then the smallest entry that is at least
+///     this big is used. By default, or if all entries are smaller, the biggest entry is used.
 ///
 /// See also
 /// --------
@@ -33,32 +45,210 @@ pub(crate) struct WrappedIcon(pub Icon);
 #[pymethods]
 impl WrappedIcon {
     #[new]
-    fn new(path: PathBuf) -> PyResult<Self> {
-        let reader = match Reader::open(path) {
-            Ok(reader) => reader,
+    fn new(py: Python, path: PathBuf, size: Option<u32>) -> PyResult<Self> {
+        let data = match py.allow_threads(|| std::fs::read(path)) {
+            Ok(data) => data,
             Err(err) => {
                 let msg = format_to_string_ignore!("Could not open icon: {}", err);
                 return Err(PyErr::new::<PyValueError, _>(msg));
             },
         };
-        let img = match reader.decode() {
-            Ok(image) => image.to_rgba8(),
-            Err(err) => {
-                let msg = format_to_string_ignore!("Could not decode icon: {}", err);
-                return Err(PyErr::new::<PyValueError, _>(msg));
-            },
-        };
-        let icon = match Icon::from_rgba(img.to_vec(), img.width(), img.height()) {
-            Ok(icon) => icon,
-            Err(err) => {
-                let msg = format_to_string_ignore!("Could not convert icon: {}", err);
-                return Err(PyErr::new::<PyValueError, _>(msg));
+        decode(py, &data, size)
+    }
+
+    /// from_bytes(data, *, size=None)
+    /// --
+    ///
+    /// Decodes an icon from the data of an image file.
+    ///
+    /// Parameters
+    /// ----------
+    /// data : bytes-like
+    ///     The data of a PNG, ICO or BMP file, e.g. read with :func:`importlib.resources.read_binary`.
+    ///     Any :class:`bytes`, :class:`bytearray` or :class:`memoryview` is accepted.
+    /// size : Optional[int]
+    ///     If the data is an ICO file with multiple entries, then the smallest entry that is at least
+    ///     this big is used. By default, or if all entries are smaller, the biggest entry is used.
+    ///
+    /// Returns
+    /// -------
+    /// Icon
+    ///     The new icon.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The data could not be decoded.
+    #[staticmethod]
+    fn from_bytes(py: Python, data: PyBuffer<u8>, size: Option<u32>) -> PyResult<Self> {
+        decode(py, &data.to_vec(py)?, size)
+    }
+
+    /// from_rgba(width, height, buffer)
+    /// --
+    ///
+    /// Creates an icon from decoded pixel data.
+    ///
+    /// Parameters
+    /// ----------
+    /// width : int
+    ///     The width of the icon in pixels.
+    /// height : int
+    ///     The height of the icon in pixels.
+    /// buffer : buffer-like
+    ///     The RGBA pixels of the icon, see :meth:`~pyiced.ImageHandle.from_pixels()`.
+    ///
+    /// Returns
+    /// -------
+    /// Icon
+    ///     The new icon.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The size of the buffer does not match the dimensions.
+    #[staticmethod]
+    fn from_rgba(py: Python, width: u32, height: u32, buffer: &PyAny) -> PyResult<Self> {
+        let pixels = read_pixels(py, width, height, buffer, PixelFormat::Rgba8)?;
+        to_icon(pixels, width, height)
+    }
+
+    /// from_handle(handle, *, size=None)
+    /// --
+    ///
+    /// Creates an icon from an image handle.
+    ///
+    /// Parameters
+    /// ----------
+    /// handle : ImageHandle
+    ///     The image to use.
+    /// size : Optional[int]
+    ///     If the image is an ICO file with multiple entries, then the smallest entry that is at least
+    ///     this big is used. By default, or if all entries are smaller, the biggest entry is used.
+    ///
+    /// Returns
+    /// -------
+    /// Icon
+    ///     The new icon.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The image could not be read or decoded.
+    #[staticmethod]
+    fn from_handle(py: Python, handle: &WrappedImageHandle, size: Option<u32>) -> PyResult<Self> {
+        match handle.0.data() {
+            Data::Path(path) => Self::new(py, path.clone(), size),
+            Data::Bytes(bytes) => decode(py, bytes, size),
+            Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                // BGRA -> RGBA is the same swap as RGBA -> BGRA
+                let pixels = PixelFormat::Rgba8.to_bgra(pixels.clone());
+                to_icon(pixels, *width, *height)
             },
-        };
-        Ok(Self(icon))
+        }
+    }
+
+    /// from_svg(handle, size)
+    /// --
+    ///
+    /// Renders an SVG into a square icon.
+    ///
+    /// The image is scaled to fit into the square, keeping its aspect ratio, and centered.
+    ///
+    /// Parameters
+    /// ----------
+    /// handle : SvgHandle
+    ///     The vector image to render.
+    /// size : int
+    ///     The width and height of the icon in pixels, e.g. 64.
+    ///
+    /// Returns
+    /// -------
+    /// Icon
+    ///     The new icon.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The SVG could not be parsed or rendered.
+    #[staticmethod]
+    fn from_svg(py: Python, handle: &WrappedSvgHandle, size: u32) -> PyResult<Self> {
+        let img = rasterize_handle(py, &handle.0, size, size)?;
+        to_icon(img.into_raw(), size, size)
     }
 
     fn __str__(&self) -> PyResult<String> {
         debug_str(&self.0)
     }
 }
+
+fn decode(py: Python, data: &[u8], size: Option<u32>) -> PyResult<WrappedIcon> {
+    let img = match py.allow_threads(|| decode_image(data, size)) {
+        Ok(img) => img,
+        Err(err) => {
+            let msg = format_to_string_ignore!("Could not decode icon: {}", err);
+            return Err(PyErr::new::<PyValueError, _>(msg));
+        },
+    };
+    let (width, height) = img.dimensions();
+    to_icon(img.into_raw(), width, height)
+}
+
+fn decode_image(data: &[u8], size: Option<u32>) -> ImageResult<RgbaImage> {
+    let data = match image::guess_format(data)? {
+        ImageFormat::Ico => select_ico_entry(data, size).map_or(Cow::Borrowed(data), Cow::Owned),
+        _ => Cow::Borrowed(data),
+    };
+    Ok(image::load_from_memory(&data)?.into_rgba8())
+}
+
+/// Copies the best matching entry of an ICO file into a new ICO file with a single entry.
+///
+/// Returns None if the file is malformed, so the decoder can report the error.
+fn select_ico_entry(data: &[u8], size: Option<u32>) -> Option<Vec<u8>> {
+    const HEADER: usize = 6;
+    const ENTRY: usize = 16;
+
+    let count = u16::from_le_bytes([*data.get(4)?, *data.get(5)?]) as usize;
+    let entries = data.get(HEADER..HEADER + count * ENTRY)?;
+    let entries = entries.chunks_exact(ENTRY).map(|entry| {
+        // a width or height of 0 means 256
+        let width = entry[0].wrapping_sub(1) as u32 + 1;
+        let height = entry[1].wrapping_sub(1) as u32 + 1;
+        let bits = u16::from_le_bytes([entry[6], entry[7]]);
+        (width.max(height), bits, entry)
+    });
+
+    let (_, _, entry) = match size {
+        Some(size) => entries.max_by_key(|&(dim, bits, _)| match dim >= size {
+            true => (true, u32::MAX - dim, bits),
+            false => (false, dim, bits),
+        }),
+        None => entries.max_by_key(|&(dim, bits, _)| (dim, bits)),
+    }?;
+
+    let length = u32::from_le_bytes([entry[8], entry[9], entry[10], entry[11]]) as usize;
+    let offset = u32::from_le_bytes([entry[12], entry[13], entry[14], entry[15]]) as usize;
+    let image = data.get(offset..offset.checked_add(length)?)?;
+
+    let mut result = Vec::with_capacity(HEADER + ENTRY + length);
+    result.extend_from_slice(&[0, 0, 1, 0, 1, 0]);
+    result.extend_from_slice(&entry[..12]);
+    result.extend_from_slice(&((HEADER + ENTRY) as u32).to_le_bytes());
+    result.extend_from_slice(image);
+    Some(result)
+}
+
+fn to_icon(rgba: Vec<u8>, width: u32, height: u32) -> PyResult<WrappedIcon> {
+    match Icon::from_rgba(rgba, width, height) {
+        Ok(icon) => Ok(WrappedIcon(icon)),
+        Err(err) => {
+            let msg = format_to_string_ignore!("Could not convert icon: {}", err);
+            Err(PyErr::new::<PyValueError, _>(msg))
+        },
+    }
+}
@@ -1,9 +1,12 @@
 use std::path::PathBuf;
 
 use iced::image::Handle;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::common::debug_str;
+use crate::format_to_string_ignore;
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<WrappedImageHandle>()?;
@@ -59,7 +62,138 @@ impl WrappedImageHandle {
         Self(Handle::from_memory(bytes))
     }
 
+    /// from_pixels(width, height, buffer, format="rgba8")
+    /// --
+    ///
+    /// Creates an image handle from decoded pixel data.
+    ///
+    /// The buffer is copied, so it can be modified or released after the call.
+    ///
+    /// Arguments
+    /// ---------
+    /// width : int
+    ///     The width of the image in pixels.
+    /// height : int
+    ///     The height of the image in pixels.
+    /// buffer : buffer-like
+    ///     Any object of unsigned bytes that implements the buffer protocol,
+    ///     e.g. :class:`bytes`, :class:`memoryview` or a numpy array with dtype ``uint8``.
+    ///
+    ///     The buffer is either flat, or has the shape ``(height, width * channels)``,
+    ///     or ``(height, width, channels)``. Its data does not need to be contiguous.
+    /// format : str
+    ///     The layout of a pixel: "rgba8", "bgra8", "rgb8" or "gray8".
+    ///
+    /// Returns
+    /// -------
+    /// ImageHandle
+    ///     The new image handle.
+    ///
+    /// Example
+    /// -------
+    /// .. code-block:: python
+    ///
+    ///     frame = camera.read()  # numpy array with shape (480, 640, 3)
+    ///     handle = ImageHandle.from_pixels(640, 480, frame, 'rgb8')
+    #[staticmethod]
+    #[args(format = "\"rgba8\"")]
+    fn from_pixels(
+        py: Python,
+        width: u32,
+        height: u32,
+        buffer: &PyAny,
+        format: &str,
+    ) -> PyResult<Self> {
+        let format = PixelFormat::from_str(format)?;
+        let pixels = format.to_bgra(read_pixels(py, width, height, buffer, format)?);
+        Ok(Self(Handle::from_pixels(width, height, pixels)))
+    }
+
     fn __str__(&self) -> PyResult<String> {
         debug_str(&self.0)
     }
 }
+
+/// Copies the pixels of a buffer-protocol object, checking that its size matches the image.
+pub(crate) fn read_pixels(
+    py: Python,
+    width: u32,
+    height: u32,
+    buffer: &PyAny,
+    format: PixelFormat,
+) -> PyResult<Vec<u8>> {
+    let buffer = PyBuffer::<u8>::get(buffer)?;
+
+    let channels = format.channels();
+    let expected = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(channels))
+        .ok_or_else(|| PyErr::new::<PyValueError, _>("The image is too big"))?;
+    let shape_ok = match *buffer.shape() {
+        [_] => true,
+        [rows, columns] => rows == height as usize && columns == expected / rows.max(1),
+        [rows, columns, depth] => {
+            rows == height as usize && columns == width as usize && depth == channels
+        },
+        _ => false,
+    };
+    if !shape_ok || buffer.item_count() != expected {
+        return Err(PyErr::new::<PyValueError, _>(format_to_string_ignore!(
+            "Expected a buffer of {}x{}x{} bytes, got shape {:?}",
+            height,
+            width,
+            channels,
+            buffer.shape(),
+        )));
+    }
+    buffer.to_vec(py)
+}
+
+/// The layout of a pixel in raw image data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PixelFormat {
+    Rgba8,
+    Bgra8,
+    Rgb8,
+    Gray8,
+}
+
+impl PixelFormat {
+    pub(crate) fn from_str(format: &str) -> PyResult<Self> {
+        match format {
+            "rgba8" => Ok(Self::Rgba8),
+            "bgra8" => Ok(Self::Bgra8),
+            "rgb8" => Ok(Self::Rgb8),
+            "gray8" => Ok(Self::Gray8),
+            _ => Err(PyErr::new::<PyValueError, _>(
+                "Expected 'format' to be one of \"rgba8\", \"bgra8\", \"rgb8\" or \"gray8\"",
+            )),
+        }
+    }
+
+    pub(crate) fn channels(self) -> usize {
+        match self {
+            Self::Rgba8 | Self::Bgra8 => 4,
+            Self::Rgb8 => 3,
+            Self::Gray8 => 1,
+        }
+    }
+
+    /// Converts the pixels into BGRA, which is expected by [`Handle::from_pixels()`].
+    pub(crate) fn to_bgra(self, mut data: Vec<u8>) -> Vec<u8> {
+        match self {
+            Self::Bgra8 => data,
+            Self::Rgba8 => {
+                for pixel in data.chunks_exact_mut(4) {
+                    pixel.swap(0, 2);
+                }
+                data
+            },
+            Self::Rgb8 => data
+                .chunks_exact(3)
+                .flat_map(|p| [p[2], p[1], p[0], 0xff])
+                .collect(),
+            Self::Gray8 => data.iter().flat_map(|&v| [v, v, v, 0xff]).collect(),
+        }
+    }
+}
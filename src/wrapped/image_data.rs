@@ -0,0 +1,441 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use iced::image::Handle;
+use image::imageops::FilterType;
+use image::io::Reader;
+use image::{DynamicImage, ImageFormat};
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::format_to_string_ignore;
+use crate::wrapped::{PixelFormat, WrappedImageHandle};
+
+pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WrappedImage>()?;
+    Ok(())
+}
+
+/// A decoded image.
+///
+/// The image is immutable. All operations return a new image.
+/// The operations release the GIL, so that they can run in another thread.
+///
+/// The supported file formats are PNG, JPEG, GIF, WebP and ICO.
+///
+/// Example
+/// -------
+/// .. code-block:: python
+///
+///     def update(self, msg, clipboard):
+///         match msg:
+///             case ('open', path):
+///                 async def load():
+///                     image = await Image.open_async(path)
+///                     return ('loaded', image.thumbnail(256, 256).to_handle())
+///                 return [load()]
+///             case ('loaded', handle):
+///                 self.__handle = handle
+///
+/// See also
+/// --------
+/// `image::DynamicImage <https://docs.rs/image/0.24.2/image/enum.DynamicImage.html>`_
+#[pyclass(name = "Image", module = "pyiced")]
+#[derive(Debug, Clone)]
+pub(crate) struct WrappedImage {
+    pub image: Arc<DynamicImage>,
+    pub format: Option<ImageFormat>,
+}
+
+impl WrappedImage {
+    fn derive(&self, image: DynamicImage) -> Self {
+        Self {
+            image: Arc::new(image),
+            format: self.format,
+        }
+    }
+}
+
+fn parse_filter(filter: &str) -> PyResult<FilterType> {
+    match filter {
+        "nearest" => Ok(FilterType::Nearest),
+        "linear" => Ok(FilterType::Triangle),
+        "cubic" => Ok(FilterType::CatmullRom),
+        "gaussian" => Ok(FilterType::Gaussian),
+        "lanczos3" => Ok(FilterType::Lanczos3),
+        _ => Err(PyErr::new::<PyValueError, _>(
+            "Expected 'filter' to be one of \"nearest\", \"linear\", \"cubic\", \"gaussian\" or \
+             \"lanczos3\"",
+        )),
+    }
+}
+
+fn run_in_executor(py: Python, function: &PyAny, argument: Py<PyAny>) -> PyResult<Py<PyAny>> {
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let future = event_loop.call_method1("run_in_executor", (py.None(), function, argument))?;
+    Ok(future.into_py(py))
+}
+
+#[pymethods]
+impl WrappedImage {
+    /// open(path)
+    /// --
+    ///
+    /// Decodes an image file.
+    ///
+    /// Arguments
+    /// ---------
+    /// path : pathlib.Path
+    ///     The path of the image file. The format is guessed from the file content.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The decoded image.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The file could not be read or decoded.
+    #[staticmethod]
+    fn open(py: Python, path: PathBuf) -> PyResult<Self> {
+        py.allow_threads(move || {
+            let reader = Reader::open(path).and_then(|reader| reader.with_guessed_format());
+            let reader = match reader {
+                Ok(reader) => reader,
+                Err(err) => {
+                    let msg = format_to_string_ignore!("Could not open image: {}", err);
+                    return Err(PyErr::new::<PyValueError, _>(msg));
+                },
+            };
+            let format = reader.format();
+            match reader.decode() {
+                Ok(image) => Ok(Self {
+                    image: Arc::new(image),
+                    format,
+                }),
+                Err(err) => {
+                    let msg = format_to_string_ignore!("Could not decode image: {}", err);
+                    Err(PyErr::new::<PyValueError, _>(msg))
+                },
+            }
+        })
+    }
+
+    /// from_bytes(data)
+    /// --
+    ///
+    /// Decodes the data of an image file.
+    ///
+    /// Arguments
+    /// ---------
+    /// data : bytes-like
+    ///     The content of an image file, e.g. :class:`bytes`, :class:`bytearray` or :class:`memoryview`.
+    ///     The format is guessed from the content.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The decoded image.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The data could not be decoded.
+    #[staticmethod]
+    fn from_bytes(py: Python, data: PyBuffer<u8>) -> PyResult<Self> {
+        let data = data.to_vec(py)?;
+        py.allow_threads(move || {
+            let format = image::guess_format(&data).ok();
+            match image::load_from_memory(&data) {
+                Ok(image) => Ok(Self {
+                    image: Arc::new(image),
+                    format,
+                }),
+                Err(err) => {
+                    let msg = format_to_string_ignore!("Could not decode image: {}", err);
+                    Err(PyErr::new::<PyValueError, _>(msg))
+                },
+            }
+        })
+    }
+
+    /// open_async(path)
+    /// --
+    ///
+    /// Same as :meth:`~pyiced.Image.open()`, but runs in the default executor of the
+    /// running event loop, e.g. in a command returned by :meth:`~pyiced.IcedApp.update()`.
+    ///
+    /// Arguments
+    /// ---------
+    /// path : pathlib.Path
+    ///     The path of the image file.
+    ///
+    /// Returns
+    /// -------
+    /// Awaitable[Image]
+    ///     The decoded image.
+    #[staticmethod]
+    fn open_async(py: Python, path: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        let function = py.get_type::<Self>().getattr("open")?;
+        run_in_executor(py, function, path)
+    }
+
+    /// from_bytes_async(data)
+    /// --
+    ///
+    /// Same as :meth:`~pyiced.Image.from_bytes()`, but runs in the default executor of the
+    /// running event loop, e.g. in a command returned by :meth:`~pyiced.IcedApp.update()`.
+    ///
+    /// Arguments
+    /// ---------
+    /// data : bytes-like
+    ///     The content of an image file.
+    ///
+    /// Returns
+    /// -------
+    /// Awaitable[Image]
+    ///     The decoded image.
+    #[staticmethod]
+    fn from_bytes_async(py: Python, data: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        let function = py.get_type::<Self>().getattr("from_bytes")?;
+        run_in_executor(py, function, data)
+    }
+
+    /// The width of the image in pixels.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The width.
+    #[getter]
+    fn width(&self) -> u32 {
+        self.image.width()
+    }
+
+    /// The height of the image in pixels.
+    ///
+    /// Returns
+    /// -------
+    /// int
+    ///     The height.
+    #[getter]
+    fn height(&self) -> u32 {
+        self.image.height()
+    }
+
+    /// The file format the image was decoded from.
+    ///
+    /// Returns
+    /// -------
+    /// Optional[str]
+    ///     E.g. "png", "jpeg", "gif", "webp" or "ico".
+    #[getter]
+    fn format(&self) -> Option<String> {
+        self.format
+            .map(|format| format_to_string_ignore!("{:?}", format).to_lowercase())
+    }
+
+    /// The layout of the pixels.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     E.g. "rgba8", "rgb8", "l8" (gray) or "rgba16".
+    #[getter]
+    fn color_type(&self) -> String {
+        format_to_string_ignore!("{:?}", self.image.color()).to_lowercase()
+    }
+
+    /// resize($self, /, width, height, *, filter="linear", keep_aspect=True)
+    /// --
+    ///
+    /// Scales the image.
+    ///
+    /// Arguments
+    /// ---------
+    /// width : int
+    ///     The new (maximum) width.
+    /// height : int
+    ///     The new (maximum) height.
+    /// filter : str
+    ///     The sampling filter: "nearest", "linear", "cubic", "gaussian" or "lanczos3".
+    /// keep_aspect : bool
+    ///     If True, the image is scaled to fit into width × height, keeping its aspect ratio.
+    ///     Otherwise it is scaled to exactly width × height.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The scaled image.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The width or height is zero.
+    #[args(filter = "\"linear\"", keep_aspect = "true")]
+    fn resize(
+        &self,
+        py: Python,
+        width: u32,
+        height: u32,
+        filter: &str,
+        keep_aspect: bool,
+    ) -> PyResult<Self> {
+        if width == 0 || height == 0 {
+            return Err(PyErr::new::<PyValueError, _>(
+                "Expected 'width' and 'height' to be positive",
+            ));
+        }
+        let filter = parse_filter(filter)?;
+        let image = &*self.image;
+        Ok(self.derive(py.allow_threads(|| match keep_aspect {
+            true => image.resize(width, height, filter),
+            false => image.resize_exact(width, height, filter),
+        })))
+    }
+
+    /// thumbnail($self, /, max_width, max_height)
+    /// --
+    ///
+    /// Scales the image down quickly, keeping its aspect ratio.
+    ///
+    /// Arguments
+    /// ---------
+    /// max_width : int
+    ///     The maximum width of the thumbnail.
+    /// max_height : int
+    ///     The maximum height of the thumbnail.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The thumbnail.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The maximum width or height is zero.
+    fn thumbnail(&self, py: Python, max_width: u32, max_height: u32) -> PyResult<Self> {
+        if max_width == 0 || max_height == 0 {
+            return Err(PyErr::new::<PyValueError, _>(
+                "Expected 'max_width' and 'max_height' to be positive",
+            ));
+        }
+        let image = &*self.image;
+        Ok(self.derive(py.allow_threads(|| image.thumbnail(max_width, max_height))))
+    }
+
+    /// crop($self, /, x, y, width, height)
+    /// --
+    ///
+    /// Cuts out a part of the image.
+    ///
+    /// Arguments
+    /// ---------
+    /// x : int
+    ///     Left edge of the part.
+    /// y : int
+    ///     Top edge of the part.
+    /// width : int
+    ///     Width of the part.
+    /// height : int
+    ///     Height of the part.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The part of the image, clipped to the bounds of the image.
+    fn crop(&self, py: Python, x: u32, y: u32, width: u32, height: u32) -> Self {
+        let image = &*self.image;
+        self.derive(py.allow_threads(|| image.crop_imm(x, y, width, height)))
+    }
+
+    /// rotate($self, /, degrees)
+    /// --
+    ///
+    /// Rotates the image clockwise.
+    ///
+    /// Arguments
+    /// ---------
+    /// degrees : int
+    ///     0, 90, 180 or 270.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The rotated image.
+    fn rotate(&self, py: Python, degrees: i32) -> PyResult<Self> {
+        let image = &*self.image;
+        let image = match degrees.rem_euclid(360) {
+            0 => return Ok(self.clone()),
+            90 => py.allow_threads(|| image.rotate90()),
+            180 => py.allow_threads(|| image.rotate180()),
+            270 => py.allow_threads(|| image.rotate270()),
+            _ => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Expected 'degrees' to be a multiple of 90",
+                ));
+            },
+        };
+        Ok(self.derive(image))
+    }
+
+    /// flip($self, /, direction)
+    /// --
+    ///
+    /// Mirrors the image.
+    ///
+    /// Arguments
+    /// ---------
+    /// direction : str
+    ///     "horizontal" to swap left and right, "vertical" to swap top and bottom.
+    ///
+    /// Returns
+    /// -------
+    /// Image
+    ///     The flipped image.
+    fn flip(&self, py: Python, direction: &str) -> PyResult<Self> {
+        let image = &*self.image;
+        let image = match direction {
+            "horizontal" => py.allow_threads(|| image.fliph()),
+            "vertical" => py.allow_threads(|| image.flipv()),
+            _ => {
+                return Err(PyErr::new::<PyValueError, _>(
+                    "Expected 'direction' to be \"horizontal\" or \"vertical\"",
+                ));
+            },
+        };
+        Ok(self.derive(image))
+    }
+
+    /// to_handle($self)
+    /// --
+    ///
+    /// Converts the image into a handle that can be displayed with :func:`~pyiced.image()`.
+    ///
+    /// Returns
+    /// -------
+    /// ImageHandle
+    ///     The new image handle.
+    fn to_handle(&self, py: Python) -> WrappedImageHandle {
+        let image = &*self.image;
+        let (width, height, pixels) = py.allow_threads(|| {
+            let rgba = image.to_rgba8();
+            let (width, height) = rgba.dimensions();
+            (width, height, PixelFormat::Rgba8.to_bgra(rgba.into_raw()))
+        });
+        WrappedImageHandle(Handle::from_pixels(width, height, pixels))
+    }
+
+    fn __repr__(&self) -> String {
+        format_to_string_ignore!(
+            "<Image {}x{} {}>",
+            self.image.width(),
+            self.image.height(),
+            self.color_type(),
+        )
+        .into_owned()
+    }
+}
@@ -16,13 +16,15 @@ macro_rules! init_mod {
 
 init_mod! {
     align -> { WrappedAlign },
+    animated_image -> { AnimatedImage, Playback, WrappedAnimatedImageHandle },
     clipboard -> { WrappedClipboard },
-    color -> { WrappedColor },
+    color -> { color_to_rgba8, WrappedColor },
     fill_mode -> { WrappedFillMode },
     font -> { font_from_list, KNOWN_FONTS, NameAndData, WrappedFont },
     horizontal_alignment -> { WrappedHorizontalAlignment },
     icon -> { WrappedIcon },
-    image -> { WrappedImageHandle },
+    image -> { read_pixels, PixelFormat, WrappedImageHandle },
+    image_data -> { WrappedImage },
     instant -> { WrappedInstant },
     length -> { WrappedLength },
     line -> { WrappedLine },
@@ -32,7 +34,7 @@ init_mod! {
     size -> { WrappedSize },
     slider_handle -> { SliderHandle, WrappedSliderHandle },
     slider_handle_shape -> { WrappedSliderHandleShape },
-    svg -> { WrappedSvgHandle },
+    svg -> { parse_svg, rasterize_handle, rasterize_svg, WrappedSvgHandle },
     text_cursor -> { WrappedTextCursor },
     tooltip_position -> { WrappedTooltipPosition },
     vertical_alignment -> { WrappedVerticalAlignment },
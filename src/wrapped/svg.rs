@@ -1,9 +1,16 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use iced::svg::Handle;
+use image::{ImageBuffer, RgbaImage};
+use parking_lot::{const_mutex, Mutex};
+use pyo3::exceptions::{PyKeyError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::common::debug_str;
+use crate::format_to_string_ignore;
+use crate::wrapped::{color_to_rgba8, PixelFormat, WrappedColor, WrappedImageHandle};
 
 pub(crate) fn init_mod(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<WrappedSvgHandle>()?;
@@ -61,7 +68,208 @@ impl WrappedSvgHandle {
         Self(Handle::from_memory(bytes))
     }
 
+    /// from_template(source, variables)
+    /// --
+    ///
+    /// Creates an SVG handle from a template, substituting variables before parsing.
+    ///
+    /// A variable ``${name}`` is replaced by the value of the key "name" in the dict.
+    /// A :class:`~pyiced.Color` is substituted as ``#rrggbb``, without its alpha channel.
+    /// Other values are converted with :class:`str`. Write ``$$`` to get a literal ``$``.
+    /// The values are XML-escaped, so they can contain e.g. ``<`` or ``"``.
+    ///
+    /// The same template and variables result in the same handle, so the SVG is parsed only once,
+    /// even if the handle is created in every :meth:`~pyiced.IcedApp.view()` call.
+    ///
+    /// Parameters
+    /// ----------
+    /// source : str
+    ///     The SVG template.
+    /// variables : dict[str, Union[Color, str, float]]
+    ///     The values to substitute.
+    ///
+    /// Returns
+    /// -------
+    /// SvgHandle
+    ///     An SVG handle usable in :func:`~pyiced.svg`.
+    ///
+    /// Raises
+    /// ------
+    /// KeyError
+    ///     A variable in the template is missing in the dict.
+    ///
+    /// Example
+    /// -------
+    /// .. code-block:: python
+    ///
+    ///     ICON = '<svg viewBox="0 0 16 16"><path fill="${fill}" d="M2 2h12v12H2z"/></svg>'
+    ///
+    ///     def view(self):
+    ///         fill = self.__theme.accent if self.__hovered else self.__theme.text
+    ///         return svg(SvgHandle.from_template(ICON, {'fill': fill}))
+    #[staticmethod]
+    fn from_template(source: &str, variables: &PyDict) -> PyResult<Self> {
+        let mut values = Vec::with_capacity(variables.len());
+        for (key, value) in variables.iter() {
+            let key: String = key.extract()?;
+            let value = match value.extract::<WrappedColor>() {
+                Ok(WrappedColor(color)) => {
+                    let [r, g, b, _] = color_to_rgba8(&color);
+                    format_to_string_ignore!("#{:02x}{:02x}{:02x}", r, g, b).into_owned()
+                },
+                Err(_) => value.str()?.to_str()?.to_owned(),
+            };
+            values.push((key, value));
+        }
+        values.sort();
+
+        let key = (source.to_owned(), values);
+        if let Some(handle) = TEMPLATES.lock().get(&key) {
+            return Ok(Self(handle.clone()));
+        }
+
+        let svg = substitute(source, &key.1)?;
+        let handle = Handle::from_memory(svg.into_bytes());
+        let mut templates = TEMPLATES.lock();
+        if templates.len() >= TEMPLATES_SIZE {
+            templates.clear();
+        }
+        templates.insert(key, handle.clone());
+        Ok(Self(handle))
+    }
+
+    /// rasterize($self, /, width, height)
+    /// --
+    ///
+    /// Renders the SVG into a pixel image.
+    ///
+    /// The image is scaled to fit into the given size, keeping its aspect ratio,
+    /// and centered. Uncovered parts of the result are transparent.
+    ///
+    /// Parameters
+    /// ----------
+    /// width : int
+    ///     The width of the result in pixels.
+    /// height : int
+    ///     The height of the result in pixels.
+    ///
+    /// Returns
+    /// -------
+    /// ImageHandle
+    ///     An image handle usable in :func:`~pyiced.image`.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The SVG could not be parsed or rendered.
+    fn rasterize(&self, py: Python, width: u32, height: u32) -> PyResult<WrappedImageHandle> {
+        let image = rasterize_handle(py, &self.0, width, height)?;
+        let (width, height) = image.dimensions();
+        let pixels = PixelFormat::Rgba8.to_bgra(image.into_raw());
+        Ok(WrappedImageHandle(iced::image::Handle::from_pixels(
+            width, height, pixels,
+        )))
+    }
+
     fn __str__(&self) -> PyResult<String> {
         debug_str(&self.0)
     }
 }
+
+pub(crate) fn parse_svg(handle: &Handle) -> Option<usvg::Tree> {
+    let options = usvg::Options::default();
+    match handle.data() {
+        iced_native::svg::Data::Path(path) => usvg::Tree::from_file(path, &options).ok(),
+        iced_native::svg::Data::Bytes(bytes) => usvg::Tree::from_data(bytes, &options).ok(),
+    }
+}
+
+/// Renders an SVG with its natural size multiplied by `zoom`.
+pub(crate) fn rasterize_svg(tree: &usvg::Tree, zoom: f32) -> Option<RgbaImage> {
+    let image = resvg::render(tree, usvg::FitTo::Zoom(zoom), None)?;
+    let (width, height) = (image.width(), image.height());
+    ImageBuffer::from_raw(width, height, image.take())
+}
+
+/// Renders an SVG centered into an image of the given size, keeping its aspect ratio.
+pub(crate) fn rasterize_svg_to_size(
+    tree: &usvg::Tree,
+    width: u32,
+    height: u32,
+) -> Option<RgbaImage> {
+    let size = tree.svg_node().size;
+    let zoom = (width as f64 / size.width()).min(height as f64 / size.height());
+    let image = rasterize_svg(tree, zoom as f32)?;
+    let mut result = RgbaImage::new(width, height);
+    let x = (width as i64 - image.width() as i64) / 2;
+    let y = (height as i64 - image.height() as i64) / 2;
+    image::imageops::overlay(&mut result, &image, x, y);
+    Some(result)
+}
+
+/// Parses and renders the SVG of a handle into an image of the given size.
+pub(crate) fn rasterize_handle(
+    py: Python,
+    handle: &Handle,
+    width: u32,
+    height: u32,
+) -> PyResult<RgbaImage> {
+    if width == 0 || height == 0 {
+        return Err(PyErr::new::<PyValueError, _>(
+            "Expected 'width' and 'height' to be positive",
+        ));
+    }
+    let image = py.allow_threads(|| {
+        let tree = parse_svg(handle).ok_or("Could not parse SVG")?;
+        rasterize_svg_to_size(&tree, width, height).ok_or("Could not render SVG")
+    });
+    image.map_err(PyErr::new::<PyValueError, _>)
+}
+
+type TemplateKey = (String, Vec<(String, String)>);
+
+/// Substituted templates. The cache is cleared when it grows too big.
+static TEMPLATES: Mutex<BTreeMap<TemplateKey, Handle>> = const_mutex(BTreeMap::new());
+
+const TEMPLATES_SIZE: usize = 256;
+
+fn substitute(source: &str, values: &[(String, String)]) -> PyResult<String> {
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(index) = rest.find('$') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if let Some(tail) = rest.strip_prefix("$$") {
+            result.push('$');
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("${") {
+            let end = tail.find('}').ok_or_else(|| {
+                PyErr::new::<PyValueError, _>("Unterminated variable in SVG template")
+            })?;
+            let name = &tail[..end];
+            match values.binary_search_by(|(key, _)| key.as_str().cmp(name)) {
+                Ok(index) => escape_xml(&values[index].1, &mut result),
+                Err(_) => return Err(PyErr::new::<PyKeyError, _>(name.to_owned())),
+            }
+            rest = &tail[end + 1..];
+        } else {
+            result.push('$');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn escape_xml(value: &str, result: &mut String) {
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            c => result.push(c),
+        }
+    }
+}